@@ -207,31 +207,17 @@ fn text(frame_count: usize, area: Rect, buf: &mut Buffer) {
             let mask_cell = mask_buf.get(col.x, col.y);
             cell.set_symbol(mask_cell.symbol());
 
-            // blend the mask cell color with the cell color
+            // blend the mask cell color with the cell color, in linear light so the fade
+            // doesn't wash out through muddy mid-tones
             let cell_color = cell.style().bg.unwrap_or(Color::Rgb(0, 0, 0));
             let mask_color = mask_cell.style().fg.unwrap_or(Color::Rgb(255, 0, 0));
 
-            let color = blend(mask_color, cell_color, percentage);
+            let color = cell_color.lerp(mask_color, percentage as f32);
             cell.set_style(Style::new().fg(color));
         }
     }
 }
 
-fn blend(mask_color: Color, cell_color: Color, percentage: f64) -> Color {
-    let Color::Rgb(mask_red, mask_green, mask_blue) = mask_color else {
-        return mask_color;
-    };
-    let Color::Rgb(cell_red, cell_green, cell_blue) = cell_color else {
-        return mask_color;
-    };
-
-    let red = mask_red as f64 * percentage + cell_red as f64 * (1.0 - percentage);
-    let green = mask_green as f64 * percentage + cell_green as f64 * (1.0 - percentage);
-    let blue = mask_blue as f64 * percentage + cell_blue as f64 * (1.0 - percentage);
-
-    Color::Rgb(red as u8, green as u8, blue as u8)
-}
-
 /// a centered rect of the given size
 fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
     let horizontal = Layout::horizontal([width]).flex(Flex::Center);
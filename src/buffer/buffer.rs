@@ -0,0 +1,476 @@
+#![warn(missing_docs)]
+use std::cmp::min;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::prelude::*;
+
+mod cell;
+pub use cell::Cell;
+
+/// A buffer that maps to the desired content of the terminal after the draw call.
+///
+/// No widget in the library interacts directly with the terminal. Instead, each of them
+/// is required to draw their state to an intermediate buffer. It is then the responsibility of
+/// the final user to flush these changes to the terminal.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Buffer {
+    /// The area represented by this buffer.
+    pub area: Rect,
+    /// The content of the buffer, stored row by row, starting at the top-left corner of `area`.
+    pub content: Vec<Cell>,
+}
+
+impl Buffer {
+    /// Returns a `Buffer` with all cells set to the default `Cell`.
+    pub fn empty(area: Rect) -> Self {
+        Self::filled(area, &Cell::default())
+    }
+
+    /// Returns a `Buffer` with all cells initialized to `cell`.
+    pub fn filled(area: Rect, cell: &Cell) -> Self {
+        let size = area.area() as usize;
+        Self {
+            area,
+            content: vec![cell.clone(); size],
+        }
+    }
+
+    /// Returns a `Buffer` containing the given lines, starting at the origin.
+    pub fn with_lines<I, S>(lines: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let lines: Vec<String> = lines.into_iter().map(|l| l.as_ref().to_owned()).collect();
+        let height = lines.len() as u16;
+        let width = lines.iter().map(|l| l.width()).max().unwrap_or_default() as u16;
+        let mut buffer = Self::empty(Rect::new(0, 0, width, height));
+        for (y, line) in lines.iter().enumerate() {
+            buffer.set_string(0, y as u16, line, Style::default());
+        }
+        buffer
+    }
+
+    /// The area covered by this buffer.
+    pub fn area(&self) -> &Rect {
+        &self.area
+    }
+
+    /// Returns the index in the `content` slice of the cell at `(x, y)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when the given `(x, y)` is outside of the buffer's area.
+    pub fn index_of(&self, x: u16, y: u16) -> usize {
+        debug_assert!(
+            x >= self.area.left()
+                && x < self.area.right()
+                && y >= self.area.top()
+                && y < self.area.bottom(),
+            "position ({x}, {y}) is outside of the buffer area {:?}",
+            self.area
+        );
+        let row = (y - self.area.y) as usize;
+        let col = (x - self.area.x) as usize;
+        row * self.area.width as usize + col
+    }
+
+    /// Returns the `(x, y)` position of the cell at `index` in the `content` slice.
+    pub fn pos_of(&self, index: usize) -> (u16, u16) {
+        debug_assert!(
+            index < self.content.len(),
+            "index {index} is out of bounds of the buffer's content (len {})",
+            self.content.len()
+        );
+        let x = index as u16 % self.area.width + self.area.x;
+        let y = index as u16 / self.area.width + self.area.y;
+        (x, y)
+    }
+
+    /// Returns a reference to the cell at `(x, y)`.
+    pub fn get(&self, x: u16, y: u16) -> &Cell {
+        &self.content[self.index_of(x, y)]
+    }
+
+    /// Returns a mutable reference to the cell at `(x, y)`.
+    pub fn get_mut(&mut self, x: u16, y: u16) -> &mut Cell {
+        let i = self.index_of(x, y);
+        &mut self.content[i]
+    }
+
+    /// Prints a string, starting at the position `(x, y)`.
+    ///
+    /// Characters that fall outside of the buffer's area are skipped. Nothing is returned; use
+    /// [`Buffer::set_stringn`] to get the next column and row that would've been written to.
+    pub fn set_string<T: AsRef<str>>(&mut self, x: u16, y: u16, string: T, style: Style) {
+        self.set_stringn(x, y, string, usize::MAX, style);
+    }
+
+    /// Prints at most the first `width` columns of a string, starting at the position `(x, y)`.
+    pub fn set_stringn<T: AsRef<str>>(
+        &mut self,
+        x: u16,
+        y: u16,
+        string: T,
+        width: usize,
+        style: Style,
+    ) -> (u16, u16) {
+        let mut index = self.index_of_opt(x, y);
+        let mut x_offset = x as usize;
+        let max_x = min(self.area.right() as usize, width.saturating_add(x as usize));
+        for symbol in string.as_ref().graphemes_or_chars() {
+            let symbol_width = symbol.width();
+            let next_x = x_offset + symbol_width.max(1);
+            if next_x > max_x {
+                break;
+            }
+            if let Some(i) = index {
+                self.content[i].set_symbol(symbol);
+                self.content[i].set_style(style);
+                for offset in 1..symbol_width {
+                    if let Some(j) = i.checked_add(offset) {
+                        if let Some(cell) = self.content.get_mut(j) {
+                            cell.reset();
+                            cell.skip = true;
+                        }
+                    }
+                }
+            }
+            x_offset = next_x;
+            index = index.map(|i| i + symbol_width.max(1));
+        }
+        (x_offset as u16, y)
+    }
+
+    fn index_of_opt(&self, x: u16, y: u16) -> Option<usize> {
+        if x >= self.area.left() && x < self.area.right() && y >= self.area.top() && y < self.area.bottom() {
+            Some(self.index_of(x, y))
+        } else {
+            None
+        }
+    }
+
+    /// Prints a line, starting at the position `(x, y)`, truncated to at most `width` columns.
+    pub fn set_line(&mut self, x: u16, y: u16, line: &Line<'_>, width: u16) -> (u16, u16) {
+        let mut remaining_width = width;
+        let mut x_offset = x;
+        for span in &line.spans {
+            if remaining_width == 0 {
+                break;
+            }
+            let (next_x, next_y) =
+                self.set_stringn(x_offset, y, &span.content, remaining_width as usize, span.style);
+            let span_width = next_x.saturating_sub(x_offset);
+            x_offset = next_x;
+            remaining_width = remaining_width.saturating_sub(span_width);
+            let _ = next_y;
+        }
+        (x_offset, y)
+    }
+
+    /// Prints a span, starting at the position `(x, y)`, truncated to at most `width` columns.
+    pub fn set_span(&mut self, x: u16, y: u16, span: &Span<'_>, width: u16) -> (u16, u16) {
+        self.set_stringn(x, y, &span.content, width as usize, span.style)
+    }
+
+    /// Sets the style of the cells in the given area.
+    ///
+    /// This is a blanket operation; it doesn't change the symbol of the cells, only their style.
+    pub fn set_style(&mut self, area: Rect, style: Style) {
+        let area = area.intersection(self.area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                self.get_mut(x, y).set_style(style);
+            }
+        }
+    }
+
+    /// Resizes the buffer so that the mapped area matches the given area and clears it.
+    pub fn resize(&mut self, area: Rect) {
+        self.content.clear();
+        self.content.resize(area.area() as usize, Cell::default());
+        self.area = area;
+    }
+
+    /// Resets all cells in the buffer to their default state.
+    pub fn reset(&mut self) {
+        for cell in &mut self.content {
+            cell.reset();
+        }
+    }
+
+    /// Copies a rectangular region from `src` into `self`, clipping to both buffers' areas.
+    ///
+    /// `src_area` is interpreted relative to `src`'s own area; `dst` is the top-left destination
+    /// position within `self`. Use this to blit content between two distinct buffers. To shift
+    /// cells around within a single buffer (e.g. to implement scrolling), use [`Buffer::scroll`]
+    /// instead, since borrowing `self` as both `&mut self` and `src: &Buffer` isn't possible.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let src = Buffer::with_lines(["abc", "def"]);
+    /// let mut dst = Buffer::empty(Rect::new(0, 0, 3, 2));
+    /// dst.copy_area(&src, Rect::new(0, 0, 3, 2), Position::new(0, 0));
+    /// assert_eq!(dst, src);
+    /// ```
+    pub fn copy_area(&mut self, src: &Buffer, src_area: Rect, dst: Position) {
+        let src_area = src_area.intersection(src.area);
+        if src_area.is_empty() {
+            return;
+        }
+
+        let dst_area = Rect {
+            x: dst.x,
+            y: dst.y,
+            width: src_area.width,
+            height: src_area.height,
+        }
+        .intersection(self.area);
+        if dst_area.is_empty() {
+            return;
+        }
+
+        // clip to the smaller of the two areas so every (row, col) offset we iterate is valid in
+        // both the source and the destination
+        let width = min(src_area.width, dst_area.width);
+        let height = min(src_area.height, dst_area.height);
+
+        // overlapping in-place copies must iterate so that cells are read before they're
+        // overwritten: bottom-up when shifting down, top-down otherwise (and likewise for
+        // columns), matching memmove's direction-sensitive handling of overlapping ranges.
+        let shifting_down = dst.y > src_area.y;
+        let shifting_right = dst.x > src_area.x;
+
+        let rows: Box<dyn Iterator<Item = u16>> = if shifting_down {
+            Box::new((0..height).rev())
+        } else {
+            Box::new(0..height)
+        };
+
+        for row in rows {
+            let cols: Box<dyn Iterator<Item = u16>> = if shifting_right {
+                Box::new((0..width).rev())
+            } else {
+                Box::new(0..width)
+            };
+            for col in cols {
+                let cell = src.get(src_area.x + col, src_area.y + row).clone();
+                *self.get_mut(dst.x + col, dst.y + row) = cell;
+            }
+        }
+
+        self.sanitize_wide_edges(dst_area);
+    }
+
+    /// Shifts the cells inside `area` by `(dx, dy)` columns/rows, filling vacated cells with
+    /// `fill`.
+    ///
+    /// Positive `dx`/`dy` shift content right/down; negative values shift it left/up. This is the
+    /// primitive behind scrollable panes and log views: rather than redrawing the whole pane each
+    /// frame, existing content is shifted in place and only the newly vacated edge needs to be
+    /// (re)drawn.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let mut buf = Buffer::with_lines(["abc", "def", "ghi"]);
+    /// buf.scroll(Rect::new(0, 0, 3, 3), 0, 1, Cell::default());
+    /// assert_eq!(buf, Buffer::with_lines(["   ", "abc", "def"]));
+    /// ```
+    pub fn scroll(&mut self, area: Rect, dx: i16, dy: i16, fill: Cell) {
+        let area = area.intersection(self.area);
+        if area.is_empty() || (dx == 0 && dy == 0) {
+            return;
+        }
+
+        let snapshot = Buffer {
+            area,
+            content: (area.top()..area.bottom())
+                .flat_map(|y| (area.left()..area.right()).map(move |x| (x, y)))
+                .map(|(x, y)| self.get(x, y).clone())
+                .collect(),
+        };
+
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let src_x = x as i32 - dx as i32;
+                let src_y = y as i32 - dy as i32;
+                let in_bounds = src_x >= area.left() as i32
+                    && src_x < area.right() as i32
+                    && src_y >= area.top() as i32
+                    && src_y < area.bottom() as i32;
+                *self.get_mut(x, y) = if in_bounds {
+                    snapshot.get(src_x as u16, src_y as u16).clone()
+                } else {
+                    fill.clone()
+                };
+            }
+        }
+
+        self.sanitize_wide_edges(area);
+    }
+
+    /// Blanks out half-copied double-width glyphs left dangling at the edges of `area`.
+    ///
+    /// A clip (from [`Buffer::copy_area`] or [`Buffer::scroll`]) can land in the middle of a
+    /// wide grapheme, leaving either its leading cell with no trailing `skip` cell after it, or a
+    /// `skip` cell with no leading cell before it. Either half on its own would render as a
+    /// mangled glyph, so both are reset to a blank cell.
+    fn sanitize_wide_edges(&mut self, area: Rect) {
+        if area.is_empty() {
+            return;
+        }
+        for y in area.top()..area.bottom() {
+            let left = area.left();
+            if self.get(left, y).skip {
+                self.get_mut(left, y).reset();
+            }
+            let right = area.right() - 1;
+            if self.get(right, y).symbol().width() > 1 {
+                self.get_mut(right, y).reset();
+            }
+        }
+    }
+}
+
+trait GraphemesOrChars {
+    fn graphemes_or_chars(&self) -> Box<dyn Iterator<Item = &str> + '_>;
+}
+
+impl GraphemesOrChars for str {
+    fn graphemes_or_chars(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        use unicode_segmentation::UnicodeSegmentation;
+        Box::new(self.graphemes(true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let buf = Buffer::empty(Rect::new(0, 0, 2, 2));
+        assert_eq!(buf.content.len(), 4);
+        assert_eq!(buf.get(0, 0).symbol(), " ");
+    }
+
+    #[test]
+    fn with_lines() {
+        let buf = Buffer::with_lines(["abc", "def"]);
+        assert_eq!(buf.area, Rect::new(0, 0, 3, 2));
+        assert_eq!(buf.get(0, 0).symbol(), "a");
+        assert_eq!(buf.get(2, 1).symbol(), "f");
+    }
+
+    #[test]
+    fn index_of() {
+        let buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+        assert_eq!(buf.index_of(0, 0), 0);
+        assert_eq!(buf.index_of(3, 0), 3);
+        assert_eq!(buf.index_of(0, 1), 4);
+    }
+
+    #[test]
+    fn pos_of() {
+        let buf = Buffer::empty(Rect::new(0, 0, 4, 4));
+        assert_eq!(buf.pos_of(0), (0, 0));
+        assert_eq!(buf.pos_of(5), (1, 1));
+    }
+
+    #[test]
+    fn set_string() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        buf.set_string(0, 0, "foo", Style::default());
+        assert_eq!(buf, Buffer::with_lines(["foo  "]));
+    }
+
+    #[test]
+    fn set_string_truncates_to_buffer_bounds() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        buf.set_string(0, 0, "foobar", Style::default());
+        assert_eq!(buf, Buffer::with_lines(["foo"]));
+    }
+
+    mod copy_area {
+        use super::*;
+
+        #[test]
+        fn copies_between_buffers() {
+            let src = Buffer::with_lines(["abc", "def"]);
+            let mut dst = Buffer::empty(Rect::new(0, 0, 3, 2));
+            dst.copy_area(&src, Rect::new(0, 0, 3, 2), Position::new(0, 0));
+            assert_eq!(dst, src);
+        }
+
+        #[test]
+        fn clips_to_destination_area() {
+            let src = Buffer::with_lines(["abc", "def"]);
+            let mut dst = Buffer::empty(Rect::new(0, 0, 2, 2));
+            dst.copy_area(&src, Rect::new(0, 0, 3, 2), Position::new(0, 0));
+            assert_eq!(dst, Buffer::with_lines(["ab", "de"]));
+        }
+
+        #[test]
+        fn clips_to_source_area() {
+            let src = Buffer::with_lines(["abc", "def"]);
+            let mut dst = Buffer::empty(Rect::new(0, 0, 3, 2));
+            dst.copy_area(&src, Rect::new(0, 0, 2, 2), Position::new(0, 0));
+            assert_eq!(dst, Buffer::with_lines(["ab ", "de "]));
+        }
+
+        #[test]
+        fn copies_from_a_snapshot_of_the_destination() {
+            let mut buf = Buffer::with_lines(["abc", "def", "   "]);
+            let area = *buf.area();
+            let snapshot = Buffer::with_lines(["abc", "def", "   "]);
+            buf.copy_area(&snapshot, Rect::new(0, 0, 3, 1), Position::new(0, 2));
+            let _ = area;
+            assert_eq!(buf, Buffer::with_lines(["abc", "def", "abc"]));
+        }
+    }
+
+    mod scroll {
+        use super::*;
+
+        #[test]
+        fn scroll_down_fills_top_with_fill_cell() {
+            let mut buf = Buffer::with_lines(["abc", "def", "ghi"]);
+            buf.scroll(Rect::new(0, 0, 3, 3), 0, 1, Cell::default());
+            assert_eq!(buf, Buffer::with_lines(["   ", "abc", "def"]));
+        }
+
+        #[test]
+        fn scroll_up_fills_bottom_with_fill_cell() {
+            let mut buf = Buffer::with_lines(["abc", "def", "ghi"]);
+            buf.scroll(Rect::new(0, 0, 3, 3), 0, -1, Cell::default());
+            assert_eq!(buf, Buffer::with_lines(["def", "ghi", "   "]));
+        }
+
+        #[test]
+        fn scroll_right_fills_left_with_fill_cell() {
+            let mut buf = Buffer::with_lines(["abc"]);
+            buf.scroll(Rect::new(0, 0, 3, 1), 1, 0, Cell::default());
+            assert_eq!(buf, Buffer::with_lines([" ab"]));
+        }
+
+        #[test]
+        fn scroll_by_zero_leaves_buffer_untouched() {
+            let mut buf = Buffer::with_lines(["abcdef"]);
+            buf.scroll(Rect::new(0, 0, 3, 1), 0, 0, Cell::default());
+            // no-op (dx=dy=0) leaves the buffer untouched, including outside `area`
+            assert_eq!(buf, Buffer::with_lines(["abcdef"]));
+        }
+
+        #[test]
+        fn scroll_by_more_than_area_clears_it() {
+            let mut buf = Buffer::with_lines(["abc", "def"]);
+            buf.scroll(Rect::new(0, 0, 3, 2), 0, 5, Cell::default());
+            assert_eq!(buf, Buffer::with_lines(["   ", "   "]));
+        }
+    }
+}
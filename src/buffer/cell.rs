@@ -0,0 +1,107 @@
+#![warn(missing_docs)]
+use crate::prelude::*;
+
+/// A buffer cell, storing a single grapheme's symbol and style.
+///
+/// A cell is what a [`Buffer`] is made of. Widgets write into cells through
+/// [`Buffer::set_string`] and friends, and the cells are what actually get diffed and flushed to
+/// the terminal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cell {
+    symbol: String,
+    /// The style of the cell.
+    pub style: Style,
+    /// Whether the cell should be skipped when copying/diffing buffers.
+    ///
+    /// This is set on the trailing half of a wide (double-width) grapheme so that the cell isn't
+    /// treated as its own, separate glyph.
+    pub skip: bool,
+}
+
+impl Cell {
+    /// Returns the symbol of the cell.
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Sets the symbol of the cell.
+    pub fn set_symbol(&mut self, symbol: &str) -> &mut Self {
+        self.symbol.clear();
+        self.symbol.push_str(symbol);
+        self
+    }
+
+    /// Sets the symbol of the cell to a single character.
+    pub fn set_char(&mut self, ch: char) -> &mut Self {
+        self.symbol.clear();
+        self.symbol.push(ch);
+        self
+    }
+
+    /// Returns the style of the cell.
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    /// Sets the style of the cell.
+    pub fn set_style<S: Into<Style>>(&mut self, style: S) -> &mut Self {
+        self.style = self.style.patch(style);
+        self
+    }
+
+    /// Resets the cell to a blank symbol with a [`Style::reset`] style and clears `skip`.
+    ///
+    /// The style differs from [`Cell::default`], whose style is [`Style::default`].
+    pub fn reset(&mut self) {
+        self.symbol.clear();
+        self.symbol.push(' ');
+        self.style = Style::reset();
+        self.skip = false;
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            symbol: " ".into(),
+            style: Style::default(),
+            skip: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default() {
+        let cell = Cell::default();
+        assert_eq!(cell.symbol(), " ");
+        assert!(!cell.skip);
+    }
+
+    #[test]
+    fn set_symbol() {
+        let mut cell = Cell::default();
+        cell.set_symbol("あ");
+        assert_eq!(cell.symbol(), "あ");
+    }
+
+    #[test]
+    fn set_char() {
+        let mut cell = Cell::default();
+        cell.set_char('x');
+        assert_eq!(cell.symbol(), "x");
+    }
+
+    #[test]
+    fn reset() {
+        let mut cell = Cell::default();
+        cell.set_symbol("x");
+        cell.skip = true;
+        cell.reset();
+        assert_eq!(cell.symbol(), " ");
+        assert!(!cell.skip);
+    }
+}
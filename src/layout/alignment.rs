@@ -0,0 +1,23 @@
+/// Vertical alignment of a rect placed inside another, for use with [`Rect::aligned`].
+///
+/// This is the vertical counterpart to [`Alignment`](super::Alignment), which only covers the
+/// horizontal axis.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::layout::VerticalAlignment;
+///
+/// let alignment = VerticalAlignment::Top;
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VerticalAlignment {
+    /// Anchor to the top edge.
+    #[default]
+    Top,
+    /// Center between the top and bottom edges.
+    Center,
+    /// Anchor to the bottom edge.
+    Bottom,
+}
@@ -0,0 +1,63 @@
+//! Easing functions for shaping the `t` parameter passed to [`Rect::lerp`](super::Rect::lerp).
+//!
+//! Each function takes and returns a value in `0.0..=1.0`; feeding the eased value into
+//! [`Rect::lerp`](super::Rect::lerp) instead of the raw, linear `t` makes a transition accelerate
+//! or decelerate instead of moving at a constant speed across frames.
+
+/// No easing: `t` passes through unchanged.
+pub fn linear(t: f32) -> f32 {
+    t
+}
+
+/// Starts slow and accelerates towards the end.
+pub fn ease_in_quad(t: f32) -> f32 {
+    t * t
+}
+
+/// Starts fast and decelerates towards the end.
+pub fn ease_out_quad(t: f32) -> f32 {
+    t * (2.0 - t)
+}
+
+/// Starts slow, speeds up through the middle, and slows back down at the end.
+pub fn ease_in_out_quad(t: f32) -> f32 {
+    if t < 0.5 {
+        2.0 * t * t
+    } else {
+        let t = -2.0 * t + 2.0;
+        1.0 - t * t / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_is_identity() {
+        assert_eq!(linear(0.0), 0.0);
+        assert_eq!(linear(0.5), 0.5);
+        assert_eq!(linear(1.0), 1.0);
+    }
+
+    #[test]
+    fn ease_in_quad_starts_slower_than_linear() {
+        assert_eq!(ease_in_quad(0.0), 0.0);
+        assert_eq!(ease_in_quad(1.0), 1.0);
+        assert!(ease_in_quad(0.5) < 0.5);
+    }
+
+    #[test]
+    fn ease_out_quad_ends_slower_than_linear() {
+        assert_eq!(ease_out_quad(0.0), 0.0);
+        assert_eq!(ease_out_quad(1.0), 1.0);
+        assert!(ease_out_quad(0.5) > 0.5);
+    }
+
+    #[test]
+    fn ease_in_out_quad_endpoints_and_midpoint() {
+        assert_eq!(ease_in_out_quad(0.0), 0.0);
+        assert_eq!(ease_in_out_quad(1.0), 1.0);
+        assert_eq!(ease_in_out_quad(0.5), 0.5);
+    }
+}
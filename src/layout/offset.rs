@@ -0,0 +1,13 @@
+/// Amounts by which to move a `Rect` or [`Position`](crate::layout::Position).
+///
+/// Positive numbers move to the right/bottom and negative to the left/top.
+///
+/// See [`Rect::offset`](crate::layout::Rect::offset) and the `Add`/`Sub` impls on
+/// [`Position`](crate::layout::Position).
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Offset {
+    /// How much to move on the X axis
+    pub x: i32,
+    /// How much to move on the Y axis
+    pub y: i32,
+}
@@ -0,0 +1,103 @@
+/// Defines the padding to apply to each side of a [`Rect`](super::Rect).
+///
+/// Unlike [`Margin`](super::Margin), which insets both sides of each axis by the same amount,
+/// `Padding` lets each of the four edges shrink independently, mirroring euclid's
+/// `SideOffsets2D`. Use it with [`Rect::padded`](super::Rect::padded) to carve out, for example, a
+/// content area that only consumes space from the top edge for a title bar.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::layout::Padding;
+///
+/// let padding = Padding::new(1, 1, 2, 0);
+/// let padding = Padding::uniform(1);
+/// let padding = Padding::horizontal(1);
+/// let padding = Padding::vertical(1);
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Padding {
+    /// Left padding
+    pub left: u16,
+    /// Right padding
+    pub right: u16,
+    /// Top padding
+    pub top: u16,
+    /// Bottom padding
+    pub bottom: u16,
+}
+
+impl Padding {
+    /// Creates a new `Padding` by specifying the amount to remove from each side.
+    pub const fn new(left: u16, right: u16, top: u16, bottom: u16) -> Self {
+        Self {
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+
+    /// Creates a new `Padding` with the same amount removed from all four sides.
+    pub const fn uniform(value: u16) -> Self {
+        Self {
+            left: value,
+            right: value,
+            top: value,
+            bottom: value,
+        }
+    }
+
+    /// Creates a new `Padding` that only removes from the left and right sides.
+    pub const fn horizontal(value: u16) -> Self {
+        Self {
+            left: value,
+            right: value,
+            top: 0,
+            bottom: 0,
+        }
+    }
+
+    /// Creates a new `Padding` that only removes from the top and bottom sides.
+    pub const fn vertical(value: u16) -> Self {
+        Self {
+            left: 0,
+            right: 0,
+            top: value,
+            bottom: value,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            Padding::new(1, 2, 3, 4),
+            Padding {
+                left: 1,
+                right: 2,
+                top: 3,
+                bottom: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn uniform() {
+        assert_eq!(Padding::uniform(1), Padding::new(1, 1, 1, 1));
+    }
+
+    #[test]
+    fn horizontal() {
+        assert_eq!(Padding::horizontal(1), Padding::new(1, 1, 0, 0));
+    }
+
+    #[test]
+    fn vertical() {
+        assert_eq!(Padding::vertical(1), Padding::new(0, 0, 1, 1));
+    }
+}
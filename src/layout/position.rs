@@ -1,5 +1,7 @@
 #![warn(missing_docs)]
-use crate::layout::Rect;
+use std::ops::{Add, Sub};
+
+use crate::layout::{Offset, Rect};
 
 /// Position in the terminal
 ///
@@ -41,6 +43,79 @@ impl Position {
     pub fn new(x: u16, y: u16) -> Self {
         Position { x, y }
     }
+
+    /// Clamps the position to the nearest point within `other`.
+    ///
+    /// If `other` has zero width or height, there's no point inside it on that axis to clamp to,
+    /// so the corresponding coordinate is clamped to `other`'s left/top edge instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::layout::{Position, Rect};
+    ///
+    /// let position = Position::new(0, 0).clamp(Rect::new(2, 2, 4, 4));
+    /// assert_eq!(position, Position::new(2, 2));
+    /// ```
+    pub fn clamp(self, other: Rect) -> Self {
+        let x = if other.width == 0 {
+            other.left()
+        } else {
+            self.x.clamp(other.left(), other.right().saturating_sub(1))
+        };
+        let y = if other.height == 0 {
+            other.top()
+        } else {
+            self.y.clamp(other.top(), other.bottom().saturating_sub(1))
+        };
+        Self { x, y }
+    }
+
+    /// Converts an absolute position into one relative to the top left corner of `area`.
+    ///
+    /// This is the inverse of adding `area`'s top left corner back via
+    /// [`Position::offset_from`](Position::offset_from); it's useful for translating terminal
+    /// coordinates (e.g. from a mouse event) into coordinates local to a widget's area.
+    pub fn offset_in(self, area: Rect) -> Self {
+        Self {
+            x: self.x.saturating_sub(area.x),
+            y: self.y.saturating_sub(area.y),
+        }
+    }
+
+    /// Converts a position relative to the top left corner of `area` back into an absolute one.
+    ///
+    /// This is the inverse of [`Position::offset_in`].
+    pub fn offset_from(self, area: Rect) -> Self {
+        Self {
+            x: self.x.saturating_add(area.x),
+            y: self.y.saturating_add(area.y),
+        }
+    }
+}
+
+impl Add<Offset> for Position {
+    type Output = Self;
+
+    /// Moves the position by the given offset, saturating at the `u16` bounds.
+    fn add(self, offset: Offset) -> Self {
+        Self {
+            x: i32::from(self.x).saturating_add(offset.x).clamp(0, i32::from(u16::MAX)) as u16,
+            y: i32::from(self.y).saturating_add(offset.y).clamp(0, i32::from(u16::MAX)) as u16,
+        }
+    }
+}
+
+impl Sub<Offset> for Position {
+    type Output = Self;
+
+    /// Moves the position by the negated offset, saturating at the `u16` bounds.
+    fn sub(self, offset: Offset) -> Self {
+        Self {
+            x: i32::from(self.x).saturating_sub(offset.x).clamp(0, i32::from(u16::MAX)) as u16,
+            y: i32::from(self.y).saturating_sub(offset.y).clamp(0, i32::from(u16::MAX)) as u16,
+        }
+    }
 }
 
 impl From<(u16, u16)> for Position {
@@ -94,4 +169,63 @@ mod tests {
         assert_eq!(position.x, 1);
         assert_eq!(position.y, 2);
     }
+
+    #[test]
+    fn clamp() {
+        let position = Position::new(0, 10).clamp(Rect::new(2, 2, 4, 4));
+        assert_eq!(position, Position::new(2, 5));
+    }
+
+    #[test]
+    fn clamp_already_inside() {
+        let position = Position::new(3, 3).clamp(Rect::new(2, 2, 4, 4));
+        assert_eq!(position, Position::new(3, 3));
+    }
+
+    /// Regression test: a zero-width/height rect must not panic (`right() == left()` would
+    /// otherwise make `u16::clamp`'s `min <= max` assertion fail).
+    #[test]
+    fn clamp_to_zero_sized_rect_does_not_panic() {
+        let position = Position::new(10, 10).clamp(Rect::new(5, 5, 0, 0));
+        assert_eq!(position, Position::new(5, 5));
+    }
+
+    #[test]
+    fn clamp_to_zero_width_rect_clamps_only_x_to_the_edge() {
+        let position = Position::new(10, 10).clamp(Rect::new(5, 5, 0, 4));
+        assert_eq!(position, Position::new(5, 8));
+    }
+
+    #[test]
+    fn offset_in_and_back() {
+        let area = Rect::new(5, 5, 10, 10);
+        let absolute = Position::new(8, 9);
+        let relative = absolute.offset_in(area);
+        assert_eq!(relative, Position::new(3, 4));
+        assert_eq!(relative.offset_from(area), absolute);
+    }
+
+    #[test]
+    fn offset_in_saturates_below_area() {
+        let area = Rect::new(5, 5, 10, 10);
+        assert_eq!(Position::new(0, 0).offset_in(area), Position::new(0, 0));
+    }
+
+    #[test]
+    fn add_offset() {
+        let position = Position::new(5, 5) + Offset { x: 3, y: -2 };
+        assert_eq!(position, Position::new(8, 3));
+    }
+
+    #[test]
+    fn add_offset_saturates() {
+        let position = Position::new(1, 1) + Offset { x: -5, y: -5 };
+        assert_eq!(position, Position::new(0, 0));
+    }
+
+    #[test]
+    fn sub_offset() {
+        let position = Position::new(5, 5) - Offset { x: 2, y: 2 };
+        assert_eq!(position, Position::new(3, 3));
+    }
 }
@@ -8,9 +8,18 @@ use layout::{Position, Size};
 
 use crate::prelude::*;
 
+mod alignment;
+pub use alignment::*;
+
 mod offset;
 pub use offset::*;
 
+mod padding;
+pub use padding::*;
+
+/// Easing functions for smoothing out the `t` parameter passed to [`Rect::lerp`].
+pub mod easing;
+
 /// A simple rectangle used in the computation of the layout and to give widgets a hint about the
 /// area they are supposed to render to.
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
@@ -78,6 +87,42 @@ impl Iterator for Columns {
     }
 }
 
+/// Interpolates a single `u16` component between `a` and `b` at `t`, rounding to the nearest
+/// integer and saturating the result into `u16`. `t` is expected to already be clamped to
+/// `0.0..=1.0` by the caller.
+fn lerp_u16(a: u16, b: u16, t: f32) -> u16 {
+    let value = f32::from(a) + (f32::from(b) - f32::from(a)) * t;
+    value.round().clamp(0.0, u16::MAX as f32) as u16
+}
+
+/// An iterator over rects interpolated between two endpoints, created by [`Rect::lerp_iter`].
+pub struct LerpIter {
+    start: Rect,
+    end: Rect,
+    steps: usize,
+    current: usize,
+}
+
+impl Iterator for LerpIter {
+    type Item = Rect;
+
+    /// Retrieves the next interpolated rect.
+    ///
+    /// Returns `None` once `steps` rects have been produced.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current >= self.steps {
+            return None;
+        }
+        let t = if self.steps <= 1 {
+            0.0
+        } else {
+            self.current as f32 / (self.steps - 1) as f32
+        };
+        self.current += 1;
+        Some(self.start.lerp(self.end, t))
+    }
+}
+
 impl fmt::Display for Rect {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}x{}+{}+{}", self.width, self.height, self.x, self.y)
@@ -107,6 +152,50 @@ impl Rect {
         }
     }
 
+    /// Creates a new rect from its left, top, right and bottom edges.
+    ///
+    /// `right` and `bottom` are exclusive, matching the convention already used by
+    /// [`Rect::right`] and [`Rect::bottom`]: they're the first coordinate outside of the rect, not
+    /// the last coordinate inside it. If `right` is smaller than `left`, or `bottom` is smaller
+    /// than `top`, the corresponding dimension saturates to zero instead of underflowing.
+    ///
+    /// This is the inverse of [`Rect::as_ltrb`], which is handy for edge-based math like clipping
+    /// against scroll bounds without hand-computing width and height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::layout::Rect;
+    ///
+    /// assert_eq!(Rect::from_ltrb(1, 2, 4, 6), Rect::new(1, 2, 3, 4));
+    /// assert_eq!(Rect::from_ltrb(4, 2, 1, 6), Rect::new(4, 2, 0, 4));
+    /// ```
+    pub fn from_ltrb(left: u16, top: u16, right: u16, bottom: u16) -> Rect {
+        Rect {
+            x: left,
+            y: top,
+            width: right.saturating_sub(left),
+            height: bottom.saturating_sub(top),
+        }
+    }
+
+    /// Returns this rect's left, top, right and bottom edges.
+    ///
+    /// `right` and `bottom` are exclusive, the same convention as [`Rect::right`] and
+    /// [`Rect::bottom`]. This round-trips with [`Rect::from_ltrb`]: passing the four returned
+    /// values to it as separate arguments recovers `rect`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::layout::Rect;
+    ///
+    /// assert_eq!(Rect::new(1, 2, 3, 4).as_ltrb(), (1, 2, 4, 6));
+    /// ```
+    pub const fn as_ltrb(self) -> (u16, u16, u16, u16) {
+        (self.left(), self.top(), self.right(), self.bottom())
+    }
+
     /// The area of the rect. If the area is larger than the maximum value of u16, it will be
     /// clamped to u16::MAX.
     pub const fn area(self) -> u16 {
@@ -163,6 +252,38 @@ impl Rect {
         }
     }
 
+    /// Returns a new rect inside the current one, with the given [`Padding`] removed from each
+    /// side independently.
+    ///
+    /// This is the asymmetric counterpart to [`Rect::inner`]: where a [`Margin`] insets both
+    /// sides of an axis by the same amount, `Padding` lets each edge shrink by a different
+    /// amount, e.g. consuming only the top edge for a title bar. If the padding on either axis is
+    /// larger than the rect, the returned rect will have no area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::layout::{Padding, Rect};
+    ///
+    /// let rect = Rect::new(0, 0, 10, 10).padded(Padding::new(1, 1, 2, 0));
+    /// assert_eq!(rect, Rect::new(1, 2, 8, 8));
+    /// ```
+    pub fn padded(self, padding: Padding) -> Rect {
+        let horizontal = padding.left.saturating_add(padding.right);
+        let vertical = padding.top.saturating_add(padding.bottom);
+
+        if self.width < horizontal || self.height < vertical {
+            Rect::default()
+        } else {
+            Rect {
+                x: self.x.saturating_add(padding.left),
+                y: self.y.saturating_add(padding.top),
+                width: self.width.saturating_sub(horizontal),
+                height: self.height.saturating_sub(vertical),
+            }
+        }
+    }
+
     /// Moves the `Rect` without modifying its size.
     ///
     /// Moves the `Rect` according to the given offset without modifying its [`width`](Rect::width)
@@ -183,6 +304,66 @@ impl Rect {
         }
     }
 
+    /// Interpolates component-wise between `self` and `other`.
+    ///
+    /// `t` is clamped to `0.0..=1.0` first, so `t == 0.0` returns exactly `self` and `t == 1.0`
+    /// returns exactly `other`; a `NaN` `t` is treated as `0.0`. Each of `x`, `y`, `width` and
+    /// `height` is interpolated independently as `a + (b - a) * t` in `f32`, rounded to the
+    /// nearest integer and saturated back into `u16`. Unlike [`Rect::new`], the result's width and
+    /// height are not scaled down to keep its area within [`u16::MAX`].
+    ///
+    /// This is useful for animating a widget's area across frames, e.g. sliding a panel in or
+    /// expanding a popup. See [`Rect::lerp_iter`] for generating a whole sequence of frames at
+    /// once, and the [`easing`] module for shaping `t` so the animation doesn't move at a
+    /// constant speed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::layout::Rect;
+    ///
+    /// let start = Rect::new(0, 0, 10, 10);
+    /// let end = Rect::new(10, 10, 20, 20);
+    /// assert_eq!(start.lerp(end, 0.0), start);
+    /// assert_eq!(start.lerp(end, 1.0), end);
+    /// assert_eq!(start.lerp(end, 0.5), Rect::new(5, 5, 15, 15));
+    /// ```
+    pub fn lerp(self, other: Rect, t: f32) -> Rect {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        Rect {
+            x: lerp_u16(self.x, other.x, t),
+            y: lerp_u16(self.y, other.y, t),
+            width: lerp_u16(self.width, other.width, t),
+            height: lerp_u16(self.height, other.height, t),
+        }
+    }
+
+    /// Returns an iterator of `steps` rects interpolated between `self` and `other`.
+    ///
+    /// The first rect is always `self` and, if `steps` is greater than one, the last is always
+    /// `other`, with the rects in between evenly spaced by [`Rect::lerp`]. Generating a whole
+    /// sequence of frames this way is usually more convenient than calling [`Rect::lerp`]
+    /// manually for each one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::layout::Rect;
+    ///
+    /// let start = Rect::new(0, 0, 10, 10);
+    /// let end = Rect::new(10, 10, 20, 20);
+    /// let frames: Vec<Rect> = start.lerp_iter(end, 3).collect();
+    /// assert_eq!(frames, vec![start, Rect::new(5, 5, 15, 15), end]);
+    /// ```
+    pub fn lerp_iter(self, other: Rect, steps: usize) -> LerpIter {
+        LerpIter {
+            start: self,
+            end: other,
+            steps,
+            current: 0,
+        }
+    }
+
     /// Returns a new rect that contains both the current one and the given one.
     pub fn union(self, other: Rect) -> Rect {
         let x1 = min(self.x, other.x);
@@ -197,6 +378,47 @@ impl Rect {
         }
     }
 
+    /// Returns the smallest rect that encloses every rect in `rects`.
+    ///
+    /// This generalizes the pairwise [`Rect::union`] to an arbitrary number of rects in a single
+    /// pass, which is handy for computing the dirty region spanning several redrawn widgets, or
+    /// fitting a viewport around scattered markers.
+    ///
+    /// Returns [`Rect::default`] (zero area) for an empty iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::layout::Rect;
+    ///
+    /// let rects = [Rect::new(1, 2, 3, 4), Rect::new(2, 3, 4, 5)];
+    /// assert_eq!(Rect::bounding_box(rects), Rect::new(1, 2, 5, 6));
+    /// assert_eq!(Rect::bounding_box(Vec::<Rect>::new()), Rect::default());
+    /// ```
+    pub fn bounding_box(rects: impl IntoIterator<Item = Rect>) -> Rect {
+        let mut x1 = u16::MAX;
+        let mut y1 = u16::MAX;
+        let mut x2 = 0;
+        let mut y2 = 0;
+        let mut any = false;
+        for rect in rects {
+            any = true;
+            x1 = min(x1, rect.x);
+            y1 = min(y1, rect.y);
+            x2 = max(x2, rect.right());
+            y2 = max(y2, rect.bottom());
+        }
+        if !any {
+            return Rect::default();
+        }
+        Rect {
+            x: x1,
+            y: y1,
+            width: x2.saturating_sub(x1),
+            height: y2.saturating_sub(y1),
+        }
+    }
+
     /// Returns a new rect that is the intersection of the current one and the given one.
     ///
     /// If the two rects do not intersect, the returned rect will have no area.
@@ -284,6 +506,71 @@ impl Rect {
         Rect::new(x, y, width, height)
     }
 
+    /// Returns a rect of the given `size` centered within `self`.
+    ///
+    /// `size` is first clamped to fit inside `self`, so the result is always fully contained in
+    /// `self` even if the requested size is larger than the available area. This removes the
+    /// boilerplate of a nested, percentage-based [`Layout`] split just to center a modal or
+    /// confirmation popup. See [`Rect::aligned`] for anchoring to an edge instead of the center.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::layout::{Rect, Size};
+    ///
+    /// let area = Rect::new(0, 0, 10, 10);
+    /// assert_eq!(area.centered(Size::new(4, 2)), Rect::new(3, 4, 4, 2));
+    /// ```
+    pub fn centered(self, size: Size) -> Rect {
+        let width = size.width.min(self.width);
+        let height = size.height.min(self.height);
+        Rect {
+            x: self.x.saturating_add((self.width - width) / 2),
+            y: self.y.saturating_add((self.height - height) / 2),
+            width,
+            height,
+        }
+    }
+
+    /// Returns a rect of the given `size` anchored inside `self` according to `horizontal` and
+    /// `vertical`.
+    ///
+    /// `size` is first clamped to fit inside `self`, the same way [`Rect::centered`] does. Using
+    /// [`Alignment::Center`] with [`VerticalAlignment::Center`] is equivalent to [`Rect::centered`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::layout::{Alignment, Rect, Size, VerticalAlignment};
+    ///
+    /// let area = Rect::new(0, 0, 10, 10);
+    /// let size = Size::new(4, 2);
+    /// assert_eq!(
+    ///     area.aligned(size, Alignment::Right, VerticalAlignment::Bottom),
+    ///     Rect::new(6, 8, 4, 2)
+    /// );
+    /// ```
+    pub fn aligned(self, size: Size, horizontal: Alignment, vertical: VerticalAlignment) -> Rect {
+        let width = size.width.min(self.width);
+        let height = size.height.min(self.height);
+        let x = match horizontal {
+            Alignment::Left => self.x,
+            Alignment::Center => self.x.saturating_add((self.width - width) / 2),
+            Alignment::Right => self.x.saturating_add(self.width - width),
+        };
+        let y = match vertical {
+            VerticalAlignment::Top => self.y,
+            VerticalAlignment::Center => self.y.saturating_add((self.height - height) / 2),
+            VerticalAlignment::Bottom => self.y.saturating_add(self.height - height),
+        };
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
     /// Creates an iterator over rows within the `Rect`.
     ///
     /// This method returns a `Rows` iterator that allows iterating through rows of the `Rect`.
@@ -387,6 +674,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_ltrb() {
+        assert_eq!(Rect::from_ltrb(1, 2, 4, 6), Rect::new(1, 2, 3, 4));
+    }
+
+    #[test]
+    fn from_ltrb_saturates_when_right_before_left() {
+        assert_eq!(Rect::from_ltrb(4, 2, 1, 6), Rect::new(4, 2, 0, 4));
+    }
+
+    #[test]
+    fn as_ltrb() {
+        assert_eq!(Rect::new(1, 2, 3, 4).as_ltrb(), (1, 2, 4, 6));
+    }
+
+    #[test]
+    fn from_ltrb_as_ltrb_round_trip() {
+        let rect = Rect::new(1, 2, 3, 4);
+        let (left, top, right, bottom) = rect.as_ltrb();
+        assert_eq!(Rect::from_ltrb(left, top, right, bottom), rect);
+    }
+
     #[test]
     fn area() {
         assert_eq!(Rect::new(1, 2, 3, 4).area(), 12);
@@ -427,6 +736,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn padded() {
+        assert_eq!(
+            Rect::new(0, 0, 10, 10).padded(Padding::new(1, 1, 2, 0)),
+            Rect::new(1, 2, 8, 8)
+        );
+    }
+
+    #[test]
+    fn padded_collapses_when_horizontal_padding_exceeds_width() {
+        assert_eq!(
+            Rect::new(0, 0, 2, 10).padded(Padding::new(2, 2, 0, 0)),
+            Rect::default()
+        );
+    }
+
+    #[test]
+    fn padded_collapses_when_vertical_padding_exceeds_height() {
+        assert_eq!(
+            Rect::new(0, 0, 10, 2).padded(Padding::new(0, 0, 2, 2)),
+            Rect::default()
+        );
+    }
+
     #[test]
     fn offset() {
         assert_eq!(
@@ -460,6 +793,49 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case::zero(0.0, Rect::new(0, 0, 10, 10))]
+    #[case::one(1.0, Rect::new(10, 10, 20, 20))]
+    #[case::half(0.5, Rect::new(5, 5, 15, 15))]
+    #[case::above_one_clamps(1.5, Rect::new(10, 10, 20, 20))]
+    #[case::negative_clamps(-1.0, Rect::new(0, 0, 10, 10))]
+    #[case::nan_is_treated_as_zero(f32::NAN, Rect::new(0, 0, 10, 10))]
+    fn lerp(#[case] t: f32, #[case] expected: Rect) {
+        let start = Rect::new(0, 0, 10, 10);
+        let end = Rect::new(10, 10, 20, 20);
+        assert_eq!(start.lerp(end, t), expected);
+    }
+
+    #[test]
+    fn lerp_rounds_to_nearest() {
+        let start = Rect::new(0, 0, 0, 0);
+        let end = Rect::new(1, 0, 0, 0);
+        assert_eq!(start.lerp(end, 1.0 / 3.0), Rect::new(0, 0, 0, 0));
+        assert_eq!(start.lerp(end, 2.0 / 3.0), Rect::new(1, 0, 0, 0));
+    }
+
+    #[test]
+    fn lerp_iter_collects_evenly_spaced_rects() {
+        let start = Rect::new(0, 0, 10, 10);
+        let end = Rect::new(10, 10, 20, 20);
+        let frames: Vec<Rect> = start.lerp_iter(end, 3).collect();
+        assert_eq!(frames, vec![start, Rect::new(5, 5, 15, 15), end]);
+    }
+
+    #[test]
+    fn lerp_iter_with_zero_steps_is_empty() {
+        let start = Rect::new(0, 0, 10, 10);
+        let end = Rect::new(10, 10, 20, 20);
+        assert_eq!(start.lerp_iter(end, 0).collect::<Vec<_>>(), Vec::new());
+    }
+
+    #[test]
+    fn lerp_iter_with_one_step_returns_only_start() {
+        let start = Rect::new(0, 0, 10, 10);
+        let end = Rect::new(10, 10, 20, 20);
+        assert_eq!(start.lerp_iter(end, 1).collect::<Vec<_>>(), vec![start]);
+    }
+
     #[test]
     fn union() {
         assert_eq!(
@@ -468,6 +844,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn bounding_box() {
+        let rects = [
+            Rect::new(1, 2, 3, 4),
+            Rect::new(2, 3, 4, 5),
+            Rect::new(0, 10, 1, 1),
+        ];
+        assert_eq!(Rect::bounding_box(rects), Rect::new(0, 2, 6, 9));
+    }
+
+    #[test]
+    fn bounding_box_of_single_rect_is_identity() {
+        let rect = Rect::new(5, 6, 7, 8);
+        assert_eq!(Rect::bounding_box([rect]), rect);
+    }
+
+    #[test]
+    fn bounding_box_of_empty_iterator_is_default() {
+        assert_eq!(Rect::bounding_box(Vec::<Rect>::new()), Rect::default());
+    }
+
     #[test]
     fn intersection() {
         assert_eq!(
@@ -565,6 +962,21 @@ mod tests {
         let [_a, _b, _c] = Rect::new(0, 0, 2, 1).split(&layout);
     }
 
+    /// Regression test for `Rect::split` with `Constraint::Ratio`: unlike `Percentage(33)`,
+    /// dividing by exact thirds should not lose a column to rounding drift.
+    #[test]
+    fn split_with_ratio_constraint() {
+        let layout = Layout::horizontal([
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+            Constraint::Ratio(1, 3),
+        ]);
+        let [a, b, c] = Rect::new(0, 0, 9, 1).split(&layout);
+        assert_eq!(a, Rect::new(0, 0, 3, 1));
+        assert_eq!(b, Rect::new(3, 0, 3, 1));
+        assert_eq!(c, Rect::new(6, 0, 3, 1));
+    }
+
     #[rstest]
     #[case::inside(Rect::new(20, 20, 10, 10), Rect::new(20, 20, 10, 10))]
     #[case::up_left(Rect::new(5, 5, 10, 10), Rect::new(10, 10, 10, 10))]
@@ -583,6 +995,58 @@ mod tests {
         assert_eq!(rect.clamp(other), expected);
     }
 
+    #[test]
+    fn centered() {
+        let area = Rect::new(0, 0, 10, 10);
+        assert_eq!(area.centered(Size::new(4, 2)), Rect::new(3, 4, 4, 2));
+    }
+
+    #[test]
+    fn centered_clamps_oversized_size() {
+        let area = Rect::new(0, 0, 10, 10);
+        assert_eq!(area.centered(Size::new(20, 20)), area);
+    }
+
+    #[rstest]
+    #[case::top_left(Alignment::Left, VerticalAlignment::Top, Rect::new(0, 0, 4, 2))]
+    #[case::center(Alignment::Center, VerticalAlignment::Center, Rect::new(3, 4, 4, 2))]
+    #[case::bottom_right(Alignment::Right, VerticalAlignment::Bottom, Rect::new(6, 8, 4, 2))]
+    fn aligned(
+        #[case] horizontal: Alignment,
+        #[case] vertical: VerticalAlignment,
+        #[case] expected: Rect,
+    ) {
+        let area = Rect::new(0, 0, 10, 10);
+        assert_eq!(area.aligned(Size::new(4, 2), horizontal, vertical), expected);
+    }
+
+    #[test]
+    fn aligned_center_matches_centered() {
+        let area = Rect::new(0, 0, 10, 10);
+        let size = Size::new(4, 2);
+        assert_eq!(
+            area.aligned(size, Alignment::Center, VerticalAlignment::Center),
+            area.centered(size)
+        );
+    }
+
+    /// Regression test: `x + width` near `u16::MAX` must saturate, not overflow-panic.
+    #[test]
+    fn centered_does_not_overflow_near_u16_max() {
+        let area = Rect::new(u16::MAX - 3, 0, 10, 1);
+        assert_eq!(area.centered(Size::new(2, 1)), Rect::new(u16::MAX, 0, 2, 1));
+    }
+
+    /// Regression test: `x + width` near `u16::MAX` must saturate, not overflow-panic.
+    #[test]
+    fn aligned_does_not_overflow_near_u16_max() {
+        let area = Rect::new(u16::MAX - 3, 0, 10, 1);
+        assert_eq!(
+            area.aligned(Size::new(2, 1), Alignment::Right, VerticalAlignment::Top),
+            Rect::new(u16::MAX, 0, 2, 1)
+        );
+    }
+
     #[test]
     fn rows() {
         let area = Rect::new(0, 0, 3, 2);
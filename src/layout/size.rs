@@ -1,4 +1,9 @@
 #![warn(missing_docs)]
+use std::{
+    cmp::{max, min},
+    ops::{Div, Mul},
+};
+
 use crate::prelude::*;
 
 /// A simple size struct
@@ -18,6 +23,50 @@ impl Size {
     pub fn new(width: u16, height: u16) -> Self {
         Size { width, height }
     }
+
+    /// Returns the smallest `Size` that contains both `self` and `other`.
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            width: max(self.width, other.width),
+            height: max(self.height, other.height),
+        }
+    }
+
+    /// Returns the largest `Size` that fits within both `self` and `other`.
+    pub fn intersection(self, other: Self) -> Self {
+        Self {
+            width: min(self.width, other.width),
+            height: min(self.height, other.height),
+        }
+    }
+}
+
+impl Mul<u16> for Size {
+    type Output = Self;
+
+    /// Scales the `Size` up by `rhs`, saturating at `u16::MAX`.
+    fn mul(self, rhs: u16) -> Self {
+        Self {
+            width: self.width.saturating_mul(rhs),
+            height: self.height.saturating_mul(rhs),
+        }
+    }
+}
+
+impl Div<u16> for Size {
+    type Output = Self;
+
+    /// Scales the `Size` down by `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero, matching the behavior of integer division.
+    fn div(self, rhs: u16) -> Self {
+        Self {
+            width: self.width / rhs,
+            height: self.height / rhs,
+        }
+    }
 }
 
 impl From<(u16, u16)> for Size {
@@ -56,4 +105,31 @@ mod tests {
         assert_eq!(size.width, 10);
         assert_eq!(size.height, 20);
     }
+
+    #[test]
+    fn union() {
+        let size = Size::new(10, 20).union(Size::new(20, 10));
+        assert_eq!(size, Size::new(20, 20));
+    }
+
+    #[test]
+    fn intersection() {
+        let size = Size::new(10, 20).intersection(Size::new(20, 10));
+        assert_eq!(size, Size::new(10, 10));
+    }
+
+    #[test]
+    fn mul() {
+        assert_eq!(Size::new(10, 20) * 2, Size::new(20, 40));
+    }
+
+    #[test]
+    fn mul_saturates() {
+        assert_eq!(Size::new(u16::MAX, 1) * 2, Size::new(u16::MAX, 2));
+    }
+
+    #[test]
+    fn div() {
+        assert_eq!(Size::new(10, 21) / 2, Size::new(5, 10));
+    }
 }
@@ -0,0 +1,500 @@
+#![warn(missing_docs)]
+use std::fmt;
+
+/// ANSI Color
+///
+/// All colors from the [ANSI color table] are supported (though some names are not exactly the
+/// same as the specification), plus a [`Color::Rgb`] variant for 24-bit color and a
+/// [`Color::Indexed`] variant for accessing the full range of 256 colors.
+///
+/// [ANSI color table]: https://en.wikipedia.org/wiki/ANSI_escape_code#3-bit_and_4-bit
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Color {
+    /// Resets the color to the terminal default.
+    #[default]
+    Reset,
+    /// ANSI Color: Black. Foreground: 30, Background: 40
+    Black,
+    /// ANSI Color: Red. Foreground: 31, Background: 41
+    Red,
+    /// ANSI Color: Green. Foreground: 32, Background: 42
+    Green,
+    /// ANSI Color: Yellow. Foreground: 33, Background: 43
+    Yellow,
+    /// ANSI Color: Blue. Foreground: 34, Background: 44
+    Blue,
+    /// ANSI Color: Magenta. Foreground: 35, Background: 45
+    Magenta,
+    /// ANSI Color: Cyan. Foreground: 36, Background: 46
+    Cyan,
+    /// ANSI Color: White. Foreground: 37, Background: 47
+    ///
+    /// Note that this is sometimes called `silver` or `white` but it is actually a light gray.
+    Gray,
+    /// ANSI Color: Bright Black. Foreground: 90, Background: 100
+    DarkGray,
+    /// ANSI Color: Bright Red. Foreground: 91, Background: 101
+    LightRed,
+    /// ANSI Color: Bright Green. Foreground: 92, Background: 102
+    LightGreen,
+    /// ANSI Color: Bright Yellow. Foreground: 93, Background: 103
+    LightYellow,
+    /// ANSI Color: Bright Blue. Foreground: 94, Background: 104
+    LightBlue,
+    /// ANSI Color: Bright Magenta. Foreground: 95, Background: 105
+    LightMagenta,
+    /// ANSI Color: Bright Cyan. Foreground: 96, Background: 106
+    LightCyan,
+    /// ANSI Color: Bright White. Foreground: 97, Background: 107
+    White,
+    /// An RGB color.
+    Rgb(u8, u8, u8),
+    /// An 8-bit 256 color, as per the [xterm 256-color table].
+    ///
+    /// [xterm 256-color table]: https://jonasjacek.github.io/colors/
+    Indexed(u8),
+}
+
+/// The 16 standard ANSI colors, indexed 0-15, as used by [`Color::Indexed`] and resolved by
+/// [`Color::to_rgb`].
+const ANSI_16_RGB: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// The six color levels used by each channel of the xterm 216-color cube (indices 16-231).
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// An easing curve applied to the `t` parameter of a [`Color`] interpolation.
+///
+/// Used with [`Color::ramp`] to shape how a gradient's steps are distributed, without callers
+/// having to recompute frame math for common fades.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Easing {
+    /// Progresses at a constant rate.
+    #[default]
+    Linear,
+    /// Eases in and out, accelerating away from and decelerating into the endpoints.
+    SmoothStep,
+}
+
+impl Easing {
+    /// Applies this easing curve to `t`, which is expected to already be in `[0, 1]`.
+    pub fn apply(self, t: f32) -> f32 {
+        match self {
+            Self::Linear => t,
+            Self::SmoothStep => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
+impl Color {
+    /// Resolves this color to its 8-bit RGB channels.
+    ///
+    /// ANSI-16 colors and [`Color::Indexed`] are resolved via the standard xterm palette (the 16
+    /// named colors, the 6x6x6 color cube, and the grayscale ramp). [`Color::Reset`] resolves to
+    /// black, as there is no well-defined color to fall back to.
+    pub fn to_rgb(self) -> (u8, u8, u8) {
+        match self {
+            Self::Reset => (0, 0, 0),
+            Self::Black => ANSI_16_RGB[0],
+            Self::Red => ANSI_16_RGB[1],
+            Self::Green => ANSI_16_RGB[2],
+            Self::Yellow => ANSI_16_RGB[3],
+            Self::Blue => ANSI_16_RGB[4],
+            Self::Magenta => ANSI_16_RGB[5],
+            Self::Cyan => ANSI_16_RGB[6],
+            Self::Gray => ANSI_16_RGB[7],
+            Self::DarkGray => ANSI_16_RGB[8],
+            Self::LightRed => ANSI_16_RGB[9],
+            Self::LightGreen => ANSI_16_RGB[10],
+            Self::LightYellow => ANSI_16_RGB[11],
+            Self::LightBlue => ANSI_16_RGB[12],
+            Self::LightMagenta => ANSI_16_RGB[13],
+            Self::LightCyan => ANSI_16_RGB[14],
+            Self::White => ANSI_16_RGB[15],
+            Self::Rgb(r, g, b) => (r, g, b),
+            Self::Indexed(i) => indexed_to_rgb(i),
+        }
+    }
+
+    /// Linearly interpolates between this color and `other`, clamping `t` to `[0, 1]`.
+    ///
+    /// Non-RGB colors (ANSI-16 and [`Color::Indexed`]) are first resolved to RGB via
+    /// [`Color::to_rgb`]. The mix itself is done in linear light rather than on the raw sRGB
+    /// bytes: each channel is decoded with `(c / 255).powf(2.2)`, interpolated, and re-encoded
+    /// with `powf(1.0 / 2.2) * 255`. This avoids the muddy mid-tones a naive byte-wise lerp
+    /// produces. The result is always a [`Color::Rgb`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::style::Color;
+    /// let faded = Color::Black.lerp(Color::Rgb(255, 0, 0), 0.5);
+    /// ```
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let (r1, g1, b1) = self.to_rgb();
+        let (r2, g2, b2) = other.to_rgb();
+        Self::Rgb(
+            lerp_channel(r1, r2, t),
+            lerp_channel(g1, g2, t),
+            lerp_channel(b1, b2, t),
+        )
+    }
+
+    /// Builds a gradient of `steps` colors running from this color to `other`, shaped by
+    /// `easing`.
+    ///
+    /// Returns an empty `Vec` when `steps` is `0`. A single step returns just `self`; otherwise
+    /// the first and last colors are exactly `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::style::{Color, Easing};
+    /// let ramp = Color::Black.ramp(Color::White, 5, Easing::Linear);
+    /// assert_eq!(ramp.len(), 5);
+    /// ```
+    #[must_use]
+    pub fn ramp(self, other: Self, steps: usize, easing: Easing) -> Vec<Self> {
+        match steps {
+            0 => Vec::new(),
+            1 => vec![self],
+            steps => (0..steps)
+                .map(|i| {
+                    if i == 0 {
+                        return self;
+                    }
+                    if i == steps - 1 {
+                        return other;
+                    }
+                    let t = i as f32 / (steps - 1) as f32;
+                    self.lerp(other, easing.apply(t))
+                })
+                .collect(),
+        }
+    }
+
+    /// Maps this color to the nearest entry of `palette` by Euclidean distance in RGB.
+    ///
+    /// Non-RGB colors are first resolved via [`Color::to_rgb`]. For [`Palette::Xterm256`], both
+    /// the 6x6x6 color cube and the grayscale ramp are considered and the closer of the two is
+    /// returned. This lets apps built against true-color RGB styles gracefully degrade to
+    /// terminals that only support a 256- or 16-color backend.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::style::{Color, Palette};
+    /// let degraded = Color::Rgb(250, 10, 10).quantize(Palette::Xterm256);
+    /// ```
+    #[must_use]
+    pub fn quantize(self, palette: Palette) -> Self {
+        let (r, g, b) = self.to_rgb();
+        match palette {
+            Palette::Ansi16 => nearest_ansi16(r, g, b),
+            Palette::Xterm256 => nearest_xterm256(r, g, b),
+        }
+    }
+
+    /// Computes the WCAG relative luminance of this color: each sRGB channel is linearized, then
+    /// weighted as `0.2126*R + 0.7152*G + 0.0722*B`.
+    #[must_use]
+    pub fn relative_luminance(self) -> f32 {
+        let (r, g, b) = self.to_rgb();
+        let channel = |c: u8| {
+            let c = f32::from(c) / 255.0;
+            if c <= 0.039_28 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * channel(r) + 0.7152 * channel(g) + 0.0722 * channel(b)
+    }
+
+    /// Returns black or white, whichever contrasts better against this color as a background.
+    ///
+    /// Thresholds [`Color::relative_luminance`] at roughly `0.179`, the WCAG crossover point
+    /// below which white text reads better than black. This gives widgets a reliable way to
+    /// render legible labels over arbitrary, user-supplied background colors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::style::Color;
+    /// assert_eq!(Color::Black.contrasting(), Color::White);
+    /// assert_eq!(Color::White.contrasting(), Color::Black);
+    /// ```
+    #[must_use]
+    pub fn contrasting(self) -> Self {
+        if self.relative_luminance() > 0.179 {
+            Self::Black
+        } else {
+            Self::White
+        }
+    }
+}
+
+/// The target color set for [`Color::quantize`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Palette {
+    /// The full xterm 256-color palette: the 16 ANSI colors (unused by quantization, since the
+    /// 6x6x6 cube and grayscale ramp already cover the full color space), the 6x6x6 color cube,
+    /// and the 24-step grayscale ramp.
+    Xterm256,
+    /// Only the 16 standard ANSI colors.
+    Ansi16,
+}
+
+const ANSI_16: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+fn nearest_ansi16(r: u8, g: u8, b: u8) -> Color {
+    ANSI_16
+        .into_iter()
+        .min_by_key(|candidate| distance_sq((r, g, b), candidate.to_rgb()))
+        .expect("ANSI_16 is non-empty")
+}
+
+fn nearest_xterm256(r: u8, g: u8, b: u8) -> Color {
+    let nearest_level = |channel: u8| -> u8 {
+        CUBE_LEVELS
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, &level)| (i32::from(level) - i32::from(channel)).abs())
+            .map(|(i, _)| i as u8)
+            .expect("CUBE_LEVELS is non-empty")
+    };
+    let (rq, gq, bq) = (nearest_level(r), nearest_level(g), nearest_level(b));
+    let cube_index = 16 + 36 * rq + 6 * gq + bq;
+    let cube_rgb = (
+        CUBE_LEVELS[rq as usize],
+        CUBE_LEVELS[gq as usize],
+        CUBE_LEVELS[bq as usize],
+    );
+
+    let luma = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let gray_index =
+        (232 + (((i32::from(luma) - 8) as f32) / 10.0).round() as i32).clamp(232, 255) as u8;
+    let gray_rgb = indexed_to_rgb(gray_index);
+
+    if distance_sq((r, g, b), cube_rgb) <= distance_sq((r, g, b), gray_rgb) {
+        Color::Indexed(cube_index)
+    } else {
+        Color::Indexed(gray_index)
+    }
+}
+
+fn distance_sq(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let dr = i32::from(a.0) - i32::from(b.0);
+    let dg = i32::from(a.1) - i32::from(b.1);
+    let db = i32::from(a.2) - i32::from(b.2);
+    (dr * dr + dg * dg + db * db) as u32
+}
+
+fn lerp_channel(a: u8, b: u8, t: f32) -> u8 {
+    let mixed = to_linear(a) * (1.0 - t) + to_linear(b) * t;
+    from_linear(mixed)
+}
+
+fn to_linear(channel: u8) -> f32 {
+    (f32::from(channel) / 255.0).powf(2.2)
+}
+
+fn from_linear(channel: f32) -> u8 {
+    (channel.powf(1.0 / 2.2) * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
+fn indexed_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI_16_RGB[index as usize],
+        16..=231 => {
+            let i = index - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[(i / 6 % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        }
+        232..=255 => {
+            let level = 8 + (index - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Reset => write!(f, "Reset"),
+            Self::Black => write!(f, "Black"),
+            Self::Red => write!(f, "Red"),
+            Self::Green => write!(f, "Green"),
+            Self::Yellow => write!(f, "Yellow"),
+            Self::Blue => write!(f, "Blue"),
+            Self::Magenta => write!(f, "Magenta"),
+            Self::Cyan => write!(f, "Cyan"),
+            Self::Gray => write!(f, "Gray"),
+            Self::DarkGray => write!(f, "DarkGray"),
+            Self::LightRed => write!(f, "LightRed"),
+            Self::LightGreen => write!(f, "LightGreen"),
+            Self::LightYellow => write!(f, "LightYellow"),
+            Self::LightBlue => write!(f, "LightBlue"),
+            Self::LightMagenta => write!(f, "LightMagenta"),
+            Self::LightCyan => write!(f, "LightCyan"),
+            Self::White => write!(f, "White"),
+            Self::Rgb(r, g, b) => write!(f, "#{r:02X}{g:02X}{b:02X}"),
+            Self::Indexed(i) => write!(f, "{i}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_rgb_resolves_named_colors() {
+        assert_eq!(Color::Black.to_rgb(), (0, 0, 0));
+        assert_eq!(Color::White.to_rgb(), (255, 255, 255));
+        assert_eq!(Color::Rgb(1, 2, 3).to_rgb(), (1, 2, 3));
+    }
+
+    #[test]
+    fn to_rgb_resolves_color_cube() {
+        // index 16 is the cube's black corner, 231 is its white corner
+        assert_eq!(Color::Indexed(16).to_rgb(), (0, 0, 0));
+        assert_eq!(Color::Indexed(231).to_rgb(), (255, 255, 255));
+    }
+
+    #[test]
+    fn to_rgb_resolves_grayscale_ramp() {
+        assert_eq!(Color::Indexed(232).to_rgb(), (8, 8, 8));
+        assert_eq!(Color::Indexed(255).to_rgb(), (238, 238, 238));
+    }
+
+    #[test]
+    fn lerp_endpoints_are_exact() {
+        let a = Color::Rgb(10, 20, 30);
+        let b = Color::Rgb(200, 150, 100);
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+    }
+
+    #[test]
+    fn lerp_clamps_t() {
+        let a = Color::Rgb(0, 0, 0);
+        let b = Color::Rgb(255, 255, 255);
+        assert_eq!(a.lerp(b, -1.0), a);
+        assert_eq!(a.lerp(b, 2.0), b);
+    }
+
+    #[test]
+    fn lerp_is_gamma_correct_not_a_byte_average() {
+        // a naive byte lerp of black and white at t=0.5 would be (127 or 128, ..). Gamma-correct
+        // mixing in linear light produces a noticeably different (brighter) midpoint.
+        let midpoint = Color::Black.lerp(Color::White, 0.5);
+        assert_eq!(midpoint, Color::Rgb(186, 186, 186));
+    }
+
+    #[test]
+    fn lerp_resolves_non_rgb_colors() {
+        let midpoint = Color::Black.lerp(Color::Indexed(196), 0.5);
+        assert!(matches!(midpoint, Color::Rgb(_, _, _)));
+    }
+
+    #[test]
+    fn ramp_zero_steps_is_empty() {
+        assert_eq!(Color::Black.ramp(Color::White, 0, Easing::Linear), vec![]);
+    }
+
+    #[test]
+    fn ramp_one_step_is_self() {
+        assert_eq!(
+            Color::Black.ramp(Color::White, 1, Easing::Linear),
+            vec![Color::Black]
+        );
+    }
+
+    #[test]
+    fn ramp_includes_both_endpoints() {
+        let ramp = Color::Black.ramp(Color::White, 5, Easing::Linear);
+        assert_eq!(ramp.len(), 5);
+        assert_eq!(ramp[0], Color::Black);
+        assert_eq!(ramp[4], Color::White);
+    }
+
+    #[test]
+    fn easing_smoothstep_preserves_endpoints() {
+        assert_eq!(Easing::SmoothStep.apply(0.0), 0.0);
+        assert_eq!(Easing::SmoothStep.apply(1.0), 1.0);
+    }
+
+    #[test]
+    fn quantize_xterm256_snaps_to_cube() {
+        // pure red is exactly representable by the cube (level 255 on r, 0 elsewhere)
+        let quantized = Color::Rgb(255, 0, 0).quantize(Palette::Xterm256);
+        assert_eq!(quantized, Color::Indexed(16 + 36 * 5));
+    }
+
+    #[test]
+    fn quantize_xterm256_prefers_grayscale_for_neutral_colors() {
+        let quantized = Color::Rgb(128, 128, 128).quantize(Palette::Xterm256);
+        assert!(matches!(quantized, Color::Indexed(232..=255)));
+    }
+
+    #[test]
+    fn quantize_ansi16_snaps_to_nearest_named_color() {
+        let quantized = Color::Rgb(250, 5, 5).quantize(Palette::Ansi16);
+        assert_eq!(quantized, Color::LightRed);
+    }
+
+    #[test]
+    fn relative_luminance_of_black_and_white() {
+        assert_eq!(Color::Black.relative_luminance(), 0.0);
+        assert_eq!(Color::White.relative_luminance(), 1.0);
+    }
+
+    #[test]
+    fn contrasting_picks_white_for_dark_backgrounds() {
+        assert_eq!(Color::Black.contrasting(), Color::White);
+    }
+
+    #[test]
+    fn contrasting_picks_black_for_light_backgrounds() {
+        assert_eq!(Color::White.contrasting(), Color::Black);
+    }
+}
@@ -1,10 +1,64 @@
 #![warn(missing_docs)]
-use std::borrow::Cow;
+use std::{borrow::Cow, fmt};
 
 use itertools::{Itertools, Position};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 use crate::{prelude::*, widgets::Widget};
 
+/// An error encountered while parsing ANSI/SGR escape sequences in [`Text::parse_ansi`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseAnsiError {
+    message: String,
+}
+
+impl fmt::Display for ParseAnsiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to parse ANSI escape sequence: {}", self.message)
+    }
+}
+
+impl std::error::Error for ParseAnsiError {}
+
+/// Options controlling how [`Text::wrap`] reflows text.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui::text::WrapOptions;
+/// let options = WrapOptions::new().trim(true).break_words(true);
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct WrapOptions {
+    trim: bool,
+    break_words: bool,
+}
+
+impl WrapOptions {
+    /// Creates new `WrapOptions` with trimming and word-breaking both disabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drops whitespace at the start and end of every line produced by wrapping, including the
+    /// whitespace at each wrap point.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn trim(self, trim: bool) -> Self {
+        Self { trim, ..self }
+    }
+
+    /// Hard-breaks words that are themselves longer than the wrap width, splitting them at the
+    /// column limit instead of placing them alone on an overflowing line.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn break_words(self, break_words: bool) -> Self {
+        Self {
+            break_words,
+            ..self
+        }
+    }
+}
+
 /// A string split over multiple lines where each line is composed of several clusters, each with
 /// their own style.
 ///
@@ -54,6 +108,8 @@ pub struct Text<'a> {
     pub style: Style,
     /// The alignment of this text.
     pub alignment: Option<Alignment>,
+    /// The vertical and horizontal scroll offset, as `(y, x)`, applied when rendering.
+    pub scroll: (u16, u16),
 }
 
 impl<'a> Text<'a> {
@@ -256,6 +312,548 @@ impl<'a> Text<'a> {
             ..self
         }
     }
+
+    /// Sets the vertical and horizontal scroll offset, as `(y, x)`.
+    ///
+    /// The first `y` lines and, within each remaining line, the first `x` columns (after
+    /// accounting for the line's own alignment) are skipped when rendering. This mirrors
+    /// [`Paragraph`]'s scroll semantics, making it possible to build a lightweight scrollable
+    /// log or viewer directly on top of `Text` without going through [`Paragraph`].
+    ///
+    /// [`Paragraph`]: crate::widgets::Paragraph
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn scroll(self, offset: (u16, u16)) -> Self {
+        Self {
+            scroll: offset,
+            ..self
+        }
+    }
+
+    /// Appends a line to this text.
+    ///
+    /// `line` accepts any type that is convertible to [`Line`] (e.g. `&str`, [`String`],
+    /// [`Span`], or [`Line`] itself).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let mut text = Text::raw("The first line");
+    /// text.push_line(Line::raw("The second line"));
+    /// ```
+    pub fn push_line<T: Into<Line<'a>>>(&mut self, line: T) {
+        self.lines.push(line.into());
+    }
+
+    /// Appends a span to the last line of this text, creating a new line if the text is empty.
+    ///
+    /// This is useful when constructing a `Text` incrementally, e.g. one span at a time as
+    /// output streams in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let mut text = Text::default();
+    /// text.push_span(Span::raw("Hello, "));
+    /// text.push_span(Span::styled("world!", Style::new().bold()));
+    /// ```
+    pub fn push_span<T: Into<Span<'a>>>(&mut self, span: T) {
+        if self.lines.is_empty() {
+            self.lines.push(Line::default());
+        }
+        let last = self.lines.last_mut().expect("just ensured at least one line");
+        last.spans.push(span.into());
+    }
+
+    /// Returns an iterator over the lines of this text.
+    pub fn iter(&self) -> std::slice::Iter<'_, Line<'a>> {
+        self.lines.iter()
+    }
+
+    /// Returns a mutable iterator over the lines of this text.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Line<'a>> {
+        self.lines.iter_mut()
+    }
+
+    /// Create a styled [`Text`] from a string containing ANSI/SGR escape sequences.
+    ///
+    /// This lets output from other programs (log tailers, `ls --color`, compiler diagnostics)
+    /// be rendered with its original colors and modifiers instead of having them stripped.
+    ///
+    /// See [`Text::parse_ansi`] for a fallible version of this constructor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let text = Text::from_ansi("\x1b[31mred text\x1b[0m");
+    /// assert_eq!(text, Text::from(Span::styled("red text", Style::new().red())));
+    /// ```
+    pub fn from_ansi(content: &str) -> Text<'static> {
+        Self::parse_ansi(content).unwrap_or_else(|_| Text::raw(content.to_owned()))
+    }
+
+    /// Create a styled [`Text`] from raw bytes containing ANSI/SGR escape sequences.
+    ///
+    /// This is the entry point for output captured straight from a subprocess, a log file, or a
+    /// PTY, which isn't guaranteed to be valid UTF-8. Invalid sequences are replaced using
+    /// [`String::from_utf8_lossy`] before parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let text = Text::from_ansi_bytes(b"\x1b[31mred text\x1b[0m");
+    /// assert_eq!(text, Text::from(Span::styled("red text", Style::new().red())));
+    /// ```
+    pub fn from_ansi_bytes(content: &[u8]) -> Text<'static> {
+        Self::from_ansi(&String::from_utf8_lossy(content))
+    }
+
+    /// Parses a string containing ANSI/SGR escape sequences into a [`Text`].
+    ///
+    /// Splits the input on `\n` into [`Line`]s and produces a new [`Span`] whenever the active
+    /// style changes. Unsupported CSI sequences (anything not ending in `m`) are consumed and
+    /// ignored, and a truncated escape sequence at the end of input is treated as literal text.
+    pub fn parse_ansi(content: &str) -> Result<Text<'static>, ParseAnsiError> {
+        ansi::parse(content)
+    }
+
+    /// Serializes this text back into a string with embedded ANSI/SGR escape codes.
+    ///
+    /// The effective style of each span (this text's style, patched by its line's style, patched
+    /// by the span's own style) is emitted as an `ESC [ … m` sequence whenever it changes, with a
+    /// reset at the end of each line. Parsing the result with [`Text::from_ansi`] gives back the
+    /// same content and effective styles, but only as span styles: text and line styles are
+    /// folded into the spans, adjacent spans with the same effective style come back merged, and
+    /// removed modifiers are not encoded. This lets colorized `Text` values be written to non-TTY
+    /// sinks such as files, pagers, or CI logs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let text = Text::from(Span::styled("red text", Style::new().red()));
+    /// assert_eq!(text.to_ansi(), "\x1b[0;31mred text\x1b[0m");
+    /// ```
+    pub fn to_ansi(&self) -> String {
+        ansi::serialize(self)
+    }
+
+    /// Reflows this text so that no resulting line exceeds `width` display columns.
+    ///
+    /// Each [`Line`] is wrapped independently, walking its spans grapheme-by-grapheme and
+    /// accumulating words (runs between whitespace boundaries). Per-span styles are preserved
+    /// across break points, and the `alignment` of the source `Text` and each source `Line` is
+    /// carried over to the produced lines.
+    ///
+    /// Use [`WrapOptions`] to control whether whitespace at the edges of the produced lines is
+    /// trimmed, and whether words longer than `width` are hard-broken at the column limit.
+    ///
+    /// This lets callers pre-measure wrapped height (e.g. for layout or scrollbars) and reuse
+    /// wrapped text across frames without re-running the [`Paragraph`](crate::widgets::Paragraph)
+    /// reflow.
+    pub fn wrap(&self, width: u16, options: WrapOptions) -> Text<'a> {
+        let lines = self
+            .lines
+            .iter()
+            .flat_map(|line| wrap::wrap_line(line, width, options))
+            .collect();
+        Text {
+            lines,
+            style: self.style,
+            alignment: self.alignment,
+            scroll: self.scroll,
+        }
+    }
+}
+
+/// A small state machine for converting ANSI/SGR escape sequences into styled [`Text`].
+mod ansi {
+    use itertools::{Itertools, Position};
+
+    use super::{Line, ParseAnsiError, Span, Style, Text};
+    use crate::style::{Color, Modifier};
+
+    pub(super) fn parse(content: &str) -> Result<Text<'static>, ParseAnsiError> {
+        let mut lines = vec![];
+        let mut current_line = vec![];
+        let mut current_span = String::new();
+        let mut style = Style::default();
+
+        let mut chars = content.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\x1b' if chars.peek() == Some(&'[') => {
+                    chars.next(); // consume '['
+                    let mut params = String::new();
+                    let mut terminator = None;
+                    for c in chars.by_ref() {
+                        if c.is_ascii_alphabetic() || c == '@' || c == '~' {
+                            terminator = Some(c);
+                            break;
+                        }
+                        params.push(c);
+                    }
+                    match terminator {
+                        Some('m') => {
+                            if !current_span.is_empty() {
+                                current_line.push(Span::styled(
+                                    std::mem::take(&mut current_span),
+                                    style,
+                                ));
+                            }
+                            style = apply_sgr(style, &params);
+                        }
+                        Some(_) => {
+                            // non-SGR CSI sequence: consume and ignore
+                        }
+                        None => {
+                            // truncated escape sequence at end of input: treat as literal
+                            current_span.push('\x1b');
+                            current_span.push('[');
+                            current_span.push_str(&params);
+                        }
+                    }
+                }
+                '\n' => {
+                    if !current_span.is_empty() {
+                        current_line.push(Span::styled(std::mem::take(&mut current_span), style));
+                    }
+                    lines.push(Line::from(std::mem::take(&mut current_line)));
+                }
+                '\r' => {
+                    // treat "\r\n" as a single newline rather than an empty extra line
+                    if chars.peek() == Some(&'\n') {
+                        continue;
+                    }
+                    // a bare '\r' returns the cursor to the start of the line, so whatever was
+                    // written so far on this line gets overwritten by what follows
+                    current_span.clear();
+                    current_line.clear();
+                }
+                c => current_span.push(c),
+            }
+        }
+        if !current_span.is_empty() {
+            current_line.push(Span::styled(current_span, style));
+        }
+        if !current_line.is_empty() || lines.is_empty() {
+            lines.push(Line::from(current_line));
+        }
+        Ok(Text::from(lines))
+    }
+
+    fn apply_sgr(mut style: Style, params: &str) -> Style {
+        let params: Vec<&str> = if params.is_empty() {
+            vec!["0"]
+        } else {
+            params.split(';').collect()
+        };
+        let mut iter = params.into_iter();
+        while let Some(param) = iter.next() {
+            let code: u32 = match param.parse() {
+                Ok(code) => code,
+                Err(_) => continue,
+            };
+            match code {
+                0 => style = Style::default(),
+                1 => style = style.bold(),
+                2 => style = style.dim(),
+                22 => style = style.not_bold().not_dim(),
+                3 => style = style.italic(),
+                23 => style = style.not_italic(),
+                4 => style = style.underlined(),
+                24 => style = style.not_underlined(),
+                7 => style = style.reversed(),
+                27 => style = style.not_reversed(),
+                9 => style = style.crossed_out(),
+                29 => style = style.not_crossed_out(),
+                30..=37 => style = style.fg(ansi_color(code - 30)),
+                90..=97 => style = style.fg(ansi_color(code - 90 + 8)),
+                40..=47 => style = style.bg(ansi_color(code - 40)),
+                100..=107 => style = style.bg(ansi_color(code - 100 + 8)),
+                38 | 48 => {
+                    let Some(color) = parse_extended_color(&mut iter) else {
+                        continue;
+                    };
+                    if code == 38 {
+                        style = style.fg(color);
+                    } else {
+                        style = style.bg(color);
+                    }
+                }
+                _ => {}
+            }
+        }
+        style
+    }
+
+    fn parse_extended_color<'a>(iter: &mut impl Iterator<Item = &'a str>) -> Option<Color> {
+        match iter.next()? {
+            "5" => {
+                let n: u8 = iter.next()?.parse().ok()?;
+                Some(Color::Indexed(n))
+            }
+            "2" => {
+                let r: u8 = iter.next()?.parse().ok()?;
+                let g: u8 = iter.next()?.parse().ok()?;
+                let b: u8 = iter.next()?.parse().ok()?;
+                Some(Color::Rgb(r, g, b))
+            }
+            _ => None,
+        }
+    }
+
+    fn ansi_color(index: u32) -> Color {
+        match index {
+            0 => Color::Black,
+            1 => Color::Red,
+            2 => Color::Green,
+            3 => Color::Yellow,
+            4 => Color::Blue,
+            5 => Color::Magenta,
+            6 => Color::Cyan,
+            7 => Color::Gray,
+            8 => Color::DarkGray,
+            9 => Color::LightRed,
+            10 => Color::LightGreen,
+            11 => Color::LightYellow,
+            12 => Color::LightBlue,
+            13 => Color::LightMagenta,
+            14 => Color::LightCyan,
+            _ => Color::White,
+        }
+    }
+
+    pub(super) fn serialize(text: &Text<'_>) -> String {
+        let mut out = String::new();
+        let mut current = Style::default();
+        for (position, line) in text.lines.iter().with_position() {
+            for span in &line.spans {
+                let effective = text.style.patch(line.style).patch(span.style);
+                if effective != current {
+                    write_sgr(&mut out, effective);
+                    current = effective;
+                }
+                out.push_str(&span.content);
+            }
+            if current != Style::default() {
+                write_sgr(&mut out, Style::default());
+                current = Style::default();
+            }
+            if position != Position::Last {
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    fn write_sgr(out: &mut String, style: Style) {
+        out.push_str("\x1b[0");
+        for code in sgr_codes(style) {
+            out.push(';');
+            out.push_str(&code.to_string());
+        }
+        out.push('m');
+    }
+
+    fn sgr_codes(style: Style) -> Vec<u32> {
+        let mut codes = vec![];
+        if style.add_modifier.contains(Modifier::BOLD) {
+            codes.push(1);
+        }
+        if style.add_modifier.contains(Modifier::DIM) {
+            codes.push(2);
+        }
+        if style.add_modifier.contains(Modifier::ITALIC) {
+            codes.push(3);
+        }
+        if style.add_modifier.contains(Modifier::UNDERLINED) {
+            codes.push(4);
+        }
+        if style.add_modifier.contains(Modifier::REVERSED) {
+            codes.push(7);
+        }
+        if style.add_modifier.contains(Modifier::CROSSED_OUT) {
+            codes.push(9);
+        }
+        if let Some(fg) = style.fg {
+            codes.extend(color_codes(fg, false));
+        }
+        if let Some(bg) = style.bg {
+            codes.extend(color_codes(bg, true));
+        }
+        codes
+    }
+
+    fn color_codes(color: Color, bg: bool) -> Vec<u32> {
+        let base = if bg { 40 } else { 30 };
+        let bright_base = if bg { 100 } else { 90 };
+        match color {
+            Color::Reset => vec![if bg { 49 } else { 39 }],
+            Color::Black => vec![base],
+            Color::Red => vec![base + 1],
+            Color::Green => vec![base + 2],
+            Color::Yellow => vec![base + 3],
+            Color::Blue => vec![base + 4],
+            Color::Magenta => vec![base + 5],
+            Color::Cyan => vec![base + 6],
+            Color::Gray => vec![base + 7],
+            Color::DarkGray => vec![bright_base],
+            Color::LightRed => vec![bright_base + 1],
+            Color::LightGreen => vec![bright_base + 2],
+            Color::LightYellow => vec![bright_base + 3],
+            Color::LightBlue => vec![bright_base + 4],
+            Color::LightMagenta => vec![bright_base + 5],
+            Color::LightCyan => vec![bright_base + 6],
+            Color::White => vec![bright_base + 7],
+            Color::Indexed(n) => vec![if bg { 48 } else { 38 }, 5, n as u32],
+            Color::Rgb(r, g, b) => vec![if bg { 48 } else { 38 }, 2, r as u32, g as u32, b as u32],
+        }
+    }
+}
+
+/// Grapheme-aware word-wrapping used by [`Text::wrap`].
+mod wrap {
+    use unicode_segmentation::UnicodeSegmentation;
+    use unicode_width::UnicodeWidthStr;
+
+    use super::{Line, Span, Style, WrapOptions};
+
+    struct Token {
+        text: String,
+        width: usize,
+        style: Style,
+    }
+
+    struct Unit {
+        tokens: Vec<Token>,
+        width: usize,
+        is_whitespace: bool,
+    }
+
+    pub(super) fn wrap_line<'a>(line: &Line<'a>, width: u16, options: WrapOptions) -> Vec<Line<'a>> {
+        let width = width.max(1) as usize;
+        let units = tokenize(line);
+
+        let mut out_lines = vec![];
+        let mut current: Vec<Token> = vec![];
+        let mut current_width = 0;
+
+        for mut unit in units {
+            if unit.is_whitespace {
+                if options.trim && current.is_empty() {
+                    continue;
+                }
+                if current_width + unit.width > width && current_width > 0 {
+                    out_lines.push(flush(&mut current, line, options.trim));
+                    current_width = 0;
+                    if options.trim {
+                        continue;
+                    }
+                }
+                current_width += unit.width;
+                current.extend(unit.tokens);
+            } else if unit.width > width {
+                if current_width > 0 {
+                    out_lines.push(flush(&mut current, line, options.trim));
+                    current_width = 0;
+                }
+                if options.break_words {
+                    for token in unit.tokens {
+                        if current_width + token.width > width && current_width > 0 {
+                            out_lines.push(flush(&mut current, line, options.trim));
+                            current_width = 0;
+                        }
+                        current_width += token.width;
+                        current.push(token);
+                    }
+                } else {
+                    out_lines.push(flush(&mut unit.tokens, line, options.trim));
+                }
+            } else {
+                if current_width + unit.width > width && current_width > 0 {
+                    out_lines.push(flush(&mut current, line, options.trim));
+                    current_width = 0;
+                }
+                current_width += unit.width;
+                current.extend(unit.tokens);
+            }
+        }
+
+        if !current.is_empty() || out_lines.is_empty() {
+            out_lines.push(flush(&mut current, line, options.trim));
+        }
+        out_lines
+    }
+
+    /// Splits a line into alternating runs of non-whitespace ("words") and whitespace, each
+    /// tagged with the per-grapheme display width and the style of the span it came from.
+    fn tokenize<'a>(line: &Line<'a>) -> Vec<Unit> {
+        let mut units = vec![];
+        let mut tokens: Vec<Token> = vec![];
+        let mut unit_width = 0;
+        let mut is_whitespace = false;
+        let mut started = false;
+
+        for span in &line.spans {
+            for grapheme in span.content.graphemes(true) {
+                let grapheme_is_whitespace = grapheme.chars().all(char::is_whitespace);
+                if started && grapheme_is_whitespace != is_whitespace {
+                    units.push(Unit {
+                        tokens: std::mem::take(&mut tokens),
+                        width: unit_width,
+                        is_whitespace,
+                    });
+                    unit_width = 0;
+                }
+                is_whitespace = grapheme_is_whitespace;
+                started = true;
+                let grapheme_width = grapheme.width();
+                unit_width += grapheme_width;
+                tokens.push(Token {
+                    text: grapheme.to_owned(),
+                    width: grapheme_width,
+                    style: span.style,
+                });
+            }
+        }
+        if !tokens.is_empty() {
+            units.push(Unit {
+                tokens,
+                width: unit_width,
+                is_whitespace,
+            });
+        }
+        units
+    }
+
+    /// Merges a run of tokens into spans (grouping consecutive tokens that share a style) and
+    /// wraps them into a `Line`, carrying over the source line's alignment. When `trim` is set,
+    /// whitespace trailing the run is dropped so wrap points don't leave dangling spaces.
+    fn flush<'a>(tokens: &mut Vec<Token>, source: &Line<'a>, trim: bool) -> Line<'a> {
+        let mut tokens = std::mem::take(tokens);
+        if trim {
+            while matches!(tokens.last(), Some(token) if token.text.chars().all(char::is_whitespace))
+            {
+                tokens.pop();
+            }
+        }
+        let mut spans: Vec<Span> = vec![];
+        for token in tokens {
+            match spans.last_mut() {
+                Some(last) if last.style == token.style => {
+                    last.content.to_mut().push_str(&token.text);
+                }
+                _ => spans.push(Span::styled(token.text, token.style)),
+            }
+        }
+        let mut wrapped = Line::from(spans);
+        wrapped.alignment = source.alignment;
+        wrapped
+    }
 }
 
 impl<'a> From<String> for Text<'a> {
@@ -312,6 +910,24 @@ impl<'a> IntoIterator for Text<'a> {
     }
 }
 
+impl<'a, 'b> IntoIterator for &'b Text<'a> {
+    type Item = &'b Line<'a>;
+    type IntoIter = std::slice::Iter<'b, Line<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.lines.iter()
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b mut Text<'a> {
+    type Item = &'b mut Line<'a>;
+    type IntoIter = std::slice::IterMut<'b, Line<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.lines.iter_mut()
+    }
+}
+
 impl<'a, T> Extend<T> for Text<'a>
 where
     T: Into<Line<'a>>,
@@ -338,7 +954,13 @@ impl std::fmt::Display for Text<'_> {
 impl<'a> Widget for Text<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         buf.set_style(area, self.style);
-        for (line, row) in self.lines.into_iter().zip(area.rows()) {
+        let (scroll_y, scroll_x) = self.scroll;
+        let lines = self
+            .lines
+            .into_iter()
+            .skip(scroll_y as usize)
+            .map(|line| skip_columns(line, scroll_x));
+        for (line, row) in lines.zip(area.rows()) {
             let line_width = line.width() as u16;
 
             let x_offset = match (self.alignment, line.alignment) {
@@ -359,6 +981,38 @@ impl<'a> Widget for Text<'a> {
     }
 }
 
+/// Drops the leading `columns` display columns of a line, respecting grapheme boundaries and
+/// preserving each remaining grapheme's style.
+fn skip_columns(line: Line<'_>, columns: u16) -> Line<'_> {
+    if columns == 0 {
+        return line;
+    }
+    let mut remaining = columns as usize;
+    let mut spans = vec![];
+    for span in line.spans {
+        if remaining == 0 {
+            spans.push(span);
+            continue;
+        }
+        let mut content = String::new();
+        for grapheme in span.content.graphemes(true) {
+            if remaining > 0 {
+                remaining = remaining.saturating_sub(grapheme.width().max(1));
+                continue;
+            }
+            content.push_str(grapheme);
+        }
+        if !content.is_empty() {
+            spans.push(Span::styled(content, span.style));
+        }
+    }
+    Line {
+        spans,
+        style: line.style,
+        alignment: line.alignment,
+    }
+}
+
 impl<'a> Styled for Text<'a> {
     type Item = Text<'a>;
 
@@ -593,6 +1247,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn push_line() {
+        let mut text = Text::raw("The first line");
+        text.push_line(Line::raw("The second line"));
+        assert_eq!(
+            text.lines,
+            vec![Line::from("The first line"), Line::from("The second line")]
+        );
+    }
+
+    #[test]
+    fn push_span_appends_to_last_line() {
+        let mut text = Text::raw("Hello,");
+        text.push_span(Span::raw(" world!"));
+        assert_eq!(
+            text.lines,
+            vec![Line::from(vec![
+                Span::raw("Hello,"),
+                Span::raw(" world!")
+            ])]
+        );
+    }
+
+    #[test]
+    fn push_span_creates_line_when_empty() {
+        let mut text = Text::default();
+        text.push_span(Span::raw("Hello!"));
+        assert_eq!(text.lines, vec![Line::from(vec![Span::raw("Hello!")])]);
+    }
+
+    #[test]
+    fn iter() {
+        let text = Text::from("The first line\nThe second line");
+        let lines: Vec<_> = text.iter().collect();
+        assert_eq!(
+            lines,
+            vec![&Line::from("The first line"), &Line::from("The second line")]
+        );
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut text = Text::from("The first line\nThe second line");
+        for line in text.iter_mut() {
+            line.spans.push(Span::raw("!"));
+        }
+        assert_eq!(format!("{text}"), "The first line!\nThe second line!");
+    }
+
+    #[test]
+    fn into_iter_by_ref() {
+        let text = Text::from("The first line\nThe second line");
+        let lines: Vec<_> = (&text).into_iter().collect();
+        assert_eq!(lines.len(), 2);
+    }
+
     #[test]
     fn stylize() {
         assert_eq!(Text::default().green().style, Color::Green.into());
@@ -603,6 +1313,230 @@ mod tests {
         assert_eq!(Text::default().italic().style, Modifier::ITALIC.into());
     }
 
+    mod to_ansi {
+        use super::*;
+        use crate::style::Color;
+
+        #[test]
+        fn plain_text_has_no_escapes() {
+            let text = Text::raw("The first line\nThe second line");
+            assert_eq!(text.to_ansi(), "The first line\nThe second line");
+        }
+
+        #[test]
+        fn single_styled_span() {
+            let text = Text::from(Span::styled("red text", Style::new().fg(Color::Red)));
+            assert_eq!(text.to_ansi(), "\x1b[0;31mred text\x1b[0m");
+        }
+
+        #[test]
+        fn style_change_emits_new_escape() {
+            let text = Text::from(Line::from(vec![
+                Span::styled("red", Style::new().fg(Color::Red)),
+                Span::raw(" plain"),
+            ]));
+            assert_eq!(text.to_ansi(), "\x1b[0;31mred\x1b[0m plain");
+        }
+
+        #[test]
+        fn multiple_lines_reset_independently() {
+            let text = Text::from(vec![
+                Line::from(Span::styled("red", Style::new().fg(Color::Red))),
+                Line::from(Span::styled("green", Style::new().fg(Color::Green))),
+            ]);
+            assert_eq!(text.to_ansi(), "\x1b[0;31mred\x1b[0m\n\x1b[0;32mgreen\x1b[0m");
+        }
+
+        #[test]
+        fn round_trips_through_from_ansi() {
+            let text = Text::from(Line::from(vec![
+                Span::styled("bold red", Style::new().fg(Color::Red).bold()),
+                Span::raw(" plain"),
+            ]));
+            let round_tripped = Text::from_ansi(&text.to_ansi());
+            assert_eq!(round_tripped, text);
+        }
+    }
+
+    mod parse_ansi {
+        use super::*;
+        use crate::style::Color;
+
+        #[test]
+        fn plain_text() {
+            let text = Text::from_ansi("The first line\nThe second line");
+            assert_eq!(text, Text::raw("The first line\nThe second line"));
+        }
+
+        #[test]
+        fn single_sgr_sequence() {
+            let text = Text::from_ansi("\x1b[31mred text\x1b[0m");
+            assert_eq!(
+                text,
+                Text::from(Span::styled("red text", Style::new().fg(Color::Red)))
+            );
+        }
+
+        #[test]
+        fn multiple_attributes() {
+            let text = Text::from_ansi("\x1b[1;4;32mbold underlined green\x1b[0m");
+            assert_eq!(
+                text,
+                Text::from(Span::styled(
+                    "bold underlined green",
+                    Style::new().fg(Color::Green).bold().underlined()
+                ))
+            );
+        }
+
+        #[test]
+        fn style_reset_splits_spans() {
+            let text = Text::from_ansi("\x1b[31mred\x1b[0m plain");
+            assert_eq!(
+                text,
+                Text::from(Line::from(vec![
+                    Span::styled("red", Style::new().fg(Color::Red)),
+                    Span::raw(" plain"),
+                ]))
+            );
+        }
+
+        #[test]
+        fn indexed_and_rgb_colors() {
+            let text = Text::from_ansi("\x1b[38;5;202mindexed\x1b[0m\x1b[38;2;10;20;30mrgb\x1b[0m");
+            assert_eq!(
+                text,
+                Text::from(Line::from(vec![
+                    Span::styled("indexed", Style::new().fg(Color::Indexed(202))),
+                    Span::styled("rgb", Style::new().fg(Color::Rgb(10, 20, 30))),
+                ]))
+            );
+        }
+
+        #[test]
+        fn multiple_lines() {
+            let text = Text::from_ansi("\x1b[31mred\x1b[0m\n\x1b[32mgreen\x1b[0m");
+            assert_eq!(
+                text,
+                Text::from(vec![
+                    Line::from(Span::styled("red", Style::new().fg(Color::Red))),
+                    Line::from(Span::styled("green", Style::new().fg(Color::Green))),
+                ])
+            );
+        }
+
+        #[test]
+        fn unsupported_csi_sequence_is_ignored() {
+            let text = Text::from_ansi("\x1b[2Jcleared");
+            assert_eq!(text, Text::raw("cleared"));
+        }
+
+        #[test]
+        fn truncated_escape_is_literal() {
+            let text = Text::from_ansi("plain\x1b[31");
+            assert_eq!(text, Text::raw("plain\x1b[31"));
+        }
+
+        #[test]
+        fn carriage_return_overwrites_the_line() {
+            let text = Text::from_ansi("garbage\rreal");
+            assert_eq!(text, Text::raw("real"));
+        }
+
+        #[test]
+        fn carriage_return_followed_by_newline_is_a_single_newline() {
+            let text = Text::from_ansi("first\r\nsecond");
+            assert_eq!(text, Text::from(vec![Line::raw("first"), Line::raw("second")]));
+        }
+
+        #[test]
+        fn from_ansi_bytes_parses_escapes() {
+            let text = Text::from_ansi_bytes(b"\x1b[31mred text\x1b[0m");
+            assert_eq!(text, Text::from(Span::styled("red text", Style::new().red())));
+        }
+    }
+
+    mod wrap {
+        use super::*;
+        use crate::style::Color;
+
+        #[test]
+        fn short_line_is_unchanged() {
+            let text = Text::raw("short");
+            assert_eq!(text.wrap(10, WrapOptions::new()), text);
+        }
+
+        #[test]
+        fn wraps_on_word_boundaries() {
+            let text = Text::raw("the quick brown fox");
+            let wrapped = text.wrap(10, WrapOptions::new());
+            assert_eq!(
+                wrapped,
+                Text::from(vec![Line::from("the quick "), Line::from("brown fox")])
+            );
+        }
+
+        #[test]
+        fn trims_whitespace_at_wrap_points() {
+            let text = Text::raw("the quick brown fox");
+            let wrapped = text.wrap(10, WrapOptions::new().trim(true));
+            assert_eq!(
+                wrapped,
+                Text::from(vec![Line::from("the quick"), Line::from("brown fox")])
+            );
+        }
+
+        #[test]
+        fn overlong_word_alone_on_its_own_line() {
+            let text = Text::raw("a supercalifragilisticexpialidocious word");
+            let wrapped = text.wrap(10, WrapOptions::new().trim(true));
+            assert_eq!(
+                wrapped,
+                Text::from(vec![
+                    Line::from("a"),
+                    Line::from("supercalifragilisticexpialidocious"),
+                    Line::from("word"),
+                ])
+            );
+        }
+
+        #[test]
+        fn breaks_overlong_words_when_requested() {
+            let text = Text::raw("supercalifragilistic");
+            let wrapped = text.wrap(10, WrapOptions::new().break_words(true));
+            assert_eq!(
+                wrapped,
+                Text::from(vec![Line::from("supercalif"), Line::from("ragilistic")])
+            );
+        }
+
+        #[test]
+        fn preserves_span_styles_across_break_points() {
+            let text = Text::from(Line::from(vec![
+                Span::styled("the quick ", Style::new().fg(Color::Red)),
+                Span::styled("brown fox", Style::new().fg(Color::Blue)),
+            ]));
+            let wrapped = text.wrap(10, WrapOptions::new());
+            assert_eq!(
+                wrapped,
+                Text::from(vec![
+                    Line::from(Span::styled("the quick ", Style::new().fg(Color::Red))),
+                    Line::from(Span::styled("brown fox", Style::new().fg(Color::Blue))),
+                ])
+            );
+        }
+
+        #[test]
+        fn preserves_alignment() {
+            let text = Text::from(Line::from("the quick brown fox").alignment(Alignment::Center));
+            let wrapped = text.wrap(10, WrapOptions::new());
+            assert!(wrapped
+                .lines
+                .iter()
+                .all(|line| line.alignment == Some(Alignment::Center)));
+        }
+    }
+
     mod widget {
         use super::*;
         use crate::{assert_buffer_eq, style::Color};
@@ -698,5 +1632,31 @@ mod tests {
 
             assert_buffer_eq!(buf, expected);
         }
+
+        #[test]
+        fn render_scroll_y_skips_leading_lines() {
+            let text = Text::from("line1\nline2\nline3").scroll((1, 0));
+
+            let area = Rect::new(0, 0, 5, 2);
+            let mut buf = Buffer::empty(area);
+            text.render(area, &mut buf);
+
+            let expected_buf = Buffer::with_lines(vec!["line2", "line3"]);
+
+            assert_buffer_eq!(buf, expected_buf);
+        }
+
+        #[test]
+        fn render_scroll_x_skips_leading_columns() {
+            let text = Text::from("foobar").scroll((0, 3));
+
+            let area = Rect::new(0, 0, 3, 1);
+            let mut buf = Buffer::empty(area);
+            text.render(area, &mut buf);
+
+            let expected_buf = Buffer::with_lines(vec!["bar"]);
+
+            assert_buffer_eq!(buf, expected_buf);
+        }
     }
 }
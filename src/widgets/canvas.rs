@@ -1,4 +1,5 @@
 mod circle;
+mod filled_rectangle;
 mod line;
 mod map;
 mod points;
@@ -11,6 +12,7 @@ use itertools::Itertools;
 
 pub use self::{
     circle::Circle,
+    filled_rectangle::FilledRectangle,
     line::Line,
     map::{Map, MapResolution},
     points::Points,
@@ -22,7 +24,7 @@ use crate::{
     style::{Color, Style},
     symbols,
     text::Line as TextLine,
-    widgets::{Block, Widget},
+    widgets::{Block, StatefulWidget, Widget},
 };
 
 /// Interface for all shapes that may be drawn on a Canvas widget.
@@ -71,10 +73,21 @@ trait Grid: Debug {
     /// of the grid in the top left corner. Note that this is not the same as the (x, y) coordinates
     /// of the canvas.
     fn paint(&mut self, x: usize, y: usize, color: Color);
+    /// Paint the background color of the whole cell that the given point falls into. The point is
+    /// expressed in the same dot coordinates as [`Grid::paint`]. Grids that can only set a single
+    /// color per cell (e.g. [`CharGrid`]) leave this as a no-op, since [`Grid::paint`] already
+    /// controls that color.
+    fn paint_background(&mut self, _x: usize, _y: usize, _color: Color) {}
     /// Save the current state of the grid as a layer to be rendered
     fn save(&self) -> Layer;
     /// Reset the grid to its initial state
     fn reset(&mut self);
+    /// Configure whether colour conflicts within a single cell should be resolved by merging them
+    /// in place rather than requiring a new [`Context::layer`] per colour. Grids that only ever
+    /// store a single colour per cell anyway (e.g. [`CharGrid`]) leave this as a no-op.
+    fn set_merge_colors(&mut self, merge_colors: bool) {
+        let _ = merge_colors;
+    }
 }
 
 /// The BrailleGrid is a grid made up of cells each containing a Braille pattern.
@@ -85,7 +98,14 @@ trait Grid: Debug {
 /// will see unicode replacement characters (�) instead of braille dots.
 ///
 /// This grid type only supports a single foreground color for each 2x4 dots cell. There is no way
-/// to set the individual color of each dot in the braille pattern.
+/// to set the individual color of each dot in the braille pattern, but a background color can be
+/// painted for the whole cell via [`BrailleGrid::paint_background`].
+///
+/// Note for readers comparing this to older design notes: the "single-pass, one-allocation"
+/// rendering this grid needed for densely multi-colored shapes (avoiding a fresh [`Layer`] and
+/// `String` per [`Context::layer`] call) is exactly what [`Canvas::merge_colors`] provides — see
+/// its `merge_colors` field below. There is no separate "flat" grid variant; `merge_colors(true)`
+/// is that single pass.
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 struct BrailleGrid {
     /// width of the grid in number of terminal columns
@@ -96,9 +116,19 @@ struct BrailleGrid {
     /// this is converted to a utf16 string when converting to a layer. See
     /// <https://en.wikipedia.org/wiki/Braille_Patterns> for more info.
     utf16_code_points: Vec<u16>,
-    /// The color of each cell only supports foreground colors for now as there's no way to
-    /// individually set the background color of each dot in the braille pattern.
+    /// The foreground color of each cell. There is no way to individually set the color of each
+    /// dot in the braille pattern.
     colors: Vec<Color>,
+    /// The background color of each cell, if one has been painted via
+    /// [`BrailleGrid::paint_background`]. `None` renders as [`Color::Reset`].
+    background_colors: Vec<Option<Color>>,
+    /// When `true`, painting a dot with an explicit color (anything but [`Color::Reset`]) that
+    /// differs from the cell's current color clears the cell's previously accumulated dots instead
+    /// of mixing them, so the most recently painted color always wins the whole cell. Dots painted
+    /// with `Color::Reset` never clear or overwrite an established color, so uncolored and colored
+    /// dots can share a cell. This lets multiple colors share a single pass over the grid instead
+    /// of needing a [`Context::layer`] per color.
+    merge_colors: bool,
 }
 
 impl BrailleGrid {
@@ -111,6 +141,8 @@ impl BrailleGrid {
             height,
             utf16_code_points: vec![symbols::braille::BLANK; length],
             colors: vec![Color::Reset; length],
+            background_colors: vec![None; length],
+            merge_colors: false,
         }
     }
 }
@@ -130,24 +162,61 @@ impl Grid for BrailleGrid {
 
     fn save(&self) -> Layer {
         let string = String::from_utf16(&self.utf16_code_points).unwrap();
-        // the background color is always reset for braille patterns
-        let colors = self.colors.iter().map(|c| (*c, Color::Reset)).collect();
+        let colors = self
+            .colors
+            .iter()
+            .zip(&self.background_colors)
+            .map(|(&fg, &bg)| (fg, bg.unwrap_or(Color::Reset)))
+            .collect();
         Layer { string, colors }
     }
 
     fn reset(&mut self) {
         self.utf16_code_points.fill(symbols::braille::BLANK);
         self.colors.fill(Color::Reset);
+        self.background_colors.fill(None);
+    }
+
+    fn set_merge_colors(&mut self, merge_colors: bool) {
+        self.merge_colors = merge_colors;
+    }
+
+    fn paint_background(&mut self, x: usize, y: usize, color: Color) {
+        let index = y / 4 * self.width as usize + x / 2;
+        if let Some(c) = self.background_colors.get_mut(index) {
+            *c = Some(color);
+        }
     }
 
     fn paint(&mut self, x: usize, y: usize, color: Color) {
         let index = y / 4 * self.width as usize + x / 2;
         // using get_mut here because we are indexing the vector with usize values
         // and we want to make sure we don't panic if the index is out of bounds
+        //
+        // `Color::Reset` means "don't care about the color of this dot", so it never clears or
+        // overwrites a color a previous dot already established for the cell. This lets shapes mix
+        // explicitly-colored and uncolored dots in the same cell without losing the established
+        // color, while two different explicit colors still flatten down to whichever was painted
+        // last, exactly as if the earlier dots had been on their own `Context::layer`.
+        if self.merge_colors && color != Color::Reset {
+            if let Some(&existing) = self.colors.get(index) {
+                if existing != color {
+                    if let Some(c) = self.utf16_code_points.get_mut(index) {
+                        *c = symbols::braille::BLANK;
+                    }
+                }
+            }
+        }
         if let Some(c) = self.utf16_code_points.get_mut(index) {
             *c |= symbols::braille::DOTS[y % 4][x % 2];
         }
-        if let Some(c) = self.colors.get_mut(index) {
+        if self.merge_colors {
+            if color != Color::Reset {
+                if let Some(c) = self.colors.get_mut(index) {
+                    *c = color;
+                }
+            }
+        } else if let Some(c) = self.colors.get_mut(index) {
             *c = color;
         }
     }
@@ -342,6 +411,20 @@ impl Grid for HalfBlockGrid {
     }
 }
 
+/// Allocate a new grid of the given size for the given marker.
+fn new_grid(width: u16, height: u16, marker: symbols::Marker) -> Box<dyn Grid> {
+    let dot = symbols::DOT.chars().next().unwrap();
+    let block = symbols::block::FULL.chars().next().unwrap();
+    let bar = symbols::bar::HALF.chars().next().unwrap();
+    match marker {
+        symbols::Marker::Dot => Box::new(CharGrid::new(width, height, dot)),
+        symbols::Marker::Block => Box::new(CharGrid::new(width, height, block)),
+        symbols::Marker::Bar => Box::new(CharGrid::new(width, height, bar)),
+        symbols::Marker::Braille => Box::new(BrailleGrid::new(width, height)),
+        symbols::Marker::HalfBlock => Box::new(HalfBlockGrid::new(width, height)),
+    }
+}
+
 /// Painter is an abstraction over the [`Context`] that allows to draw shapes on the grid.
 ///
 /// It is used by the [`Shape`] trait to draw shapes on the grid. It can be useful to think of this
@@ -414,6 +497,24 @@ impl<'a, 'b> Painter<'a, 'b> {
     pub fn paint(&mut self, x: usize, y: usize, color: Color) {
         self.context.grid.paint(x, y, color);
     }
+
+    /// Paint the background color of the whole cell that the given point falls into.
+    ///
+    /// This only has a visible effect on grids that support an independent background color per
+    /// cell (currently only the [`Marker::Braille`](symbols::Marker::Braille) grid); other grids
+    /// ignore it, since their cells only ever carry the single color set by [`Painter::paint`].
+    ///
+    /// # Examples:
+    /// ```
+    /// use ratatui::{prelude::*, widgets::canvas::*};
+    ///
+    /// let mut ctx = Context::new(1, 1, [0.0, 2.0], [0.0, 2.0], symbols::Marker::Braille);
+    /// let mut painter = Painter::from(&mut ctx);
+    /// painter.paint_background(1, 3, Color::Red);
+    /// ```
+    pub fn paint_background(&mut self, x: usize, y: usize, color: Color) {
+        self.context.grid.paint_background(x, y, color);
+    }
 }
 
 impl<'a, 'b> From<&'a mut Context<'b>> for Painter<'a, 'b> {
@@ -472,16 +573,12 @@ impl<'a> Context<'a> {
         y_bounds: [f64; 2],
         marker: symbols::Marker,
     ) -> Context<'a> {
-        let dot = symbols::DOT.chars().next().unwrap();
-        let block = symbols::block::FULL.chars().next().unwrap();
-        let bar = symbols::bar::HALF.chars().next().unwrap();
-        let grid: Box<dyn Grid> = match marker {
-            symbols::Marker::Dot => Box::new(CharGrid::new(width, height, dot)),
-            symbols::Marker::Block => Box::new(CharGrid::new(width, height, block)),
-            symbols::Marker::Bar => Box::new(CharGrid::new(width, height, bar)),
-            symbols::Marker::Braille => Box::new(BrailleGrid::new(width, height)),
-            symbols::Marker::HalfBlock => Box::new(HalfBlockGrid::new(width, height)),
-        };
+        Self::with_grid(new_grid(width, height, marker), x_bounds, y_bounds)
+    }
+
+    /// Create a new Context reusing an already allocated grid, e.g. one kept around in a
+    /// [`CanvasState`] between frames, instead of building a fresh one.
+    fn with_grid(grid: Box<dyn Grid>, x_bounds: [f64; 2], y_bounds: [f64; 2]) -> Context<'a> {
         Context {
             x_bounds,
             y_bounds,
@@ -557,6 +654,10 @@ impl<'a> Context<'a> {
 /// used to print text on the canvas. Note that the text is always printed on top of the canvas and
 /// is not affected by the layers.
 ///
+/// `Canvas` is also a [`StatefulWidget`], which means you can render it with a [`CanvasState`] to
+/// reuse its grid buffers across frames instead of allocating a new one on every render.
+///
+
 /// # Examples
 ///
 /// ```
@@ -604,6 +705,8 @@ where
     paint_func: Option<F>,
     background_color: Color,
     marker: symbols::Marker,
+    custom_marker: Option<char>,
+    merge_colors: bool,
 }
 
 impl<'a, F> Default for Canvas<'a, F>
@@ -618,6 +721,8 @@ where
             paint_func: None,
             background_color: Color::Reset,
             marker: symbols::Marker::Braille,
+            custom_marker: None,
+            merge_colors: false,
         }
     }
 }
@@ -690,6 +795,72 @@ where
     /// ```
     pub fn marker(mut self, marker: symbols::Marker) -> Canvas<'a, F> {
         self.marker = marker;
+        self.custom_marker = None;
+        self
+    }
+
+    /// Draw the canvas using an arbitrary printable character instead of one of the built-in
+    /// [`symbols::Marker`] glyphs.
+    ///
+    /// This is useful when you want a distinctive look, or need a glyph that's guaranteed to
+    /// render in a constrained terminal font (e.g. `'+'`, `'*'`, `'#'`). It renders through the
+    /// same single-character-per-cell grid that backs [`Marker::Dot`]/[`Marker::Block`]/
+    /// [`Marker::Bar`], just with a caller-supplied character instead of a fixed one. Takes
+    /// priority over [`Canvas::marker`] when set; calling [`Canvas::marker`] again clears it.
+    ///
+    /// This is a `Canvas` builder rather than a `Marker::Custom(char)` variant: [`symbols::Marker`]
+    /// is a plain, `Copy` enum shared across the crate, and only [`Canvas`] needs a caller-supplied
+    /// glyph, so the character lives here instead of widening every match on `Marker` elsewhere.
+    ///
+    /// [`Marker::Dot`]: symbols::Marker::Dot
+    /// [`Marker::Block`]: symbols::Marker::Block
+    /// [`Marker::Bar`]: symbols::Marker::Bar
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, widgets::canvas::*};
+    ///
+    /// Canvas::default().custom_marker('+').paint(|ctx| {});
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn custom_marker(mut self, cell_char: char) -> Canvas<'a, F> {
+        self.custom_marker = Some(cell_char);
+        self
+    }
+
+    /// Build the grid this canvas should paint into, honoring [`Canvas::custom_marker`] over
+    /// [`Canvas::marker`] when both have been set.
+    fn new_grid(&self, width: u16, height: u16) -> Box<dyn Grid> {
+        match self.custom_marker {
+            Some(cell_char) => Box::new(CharGrid::new(width, height, cell_char)),
+            None => new_grid(width, height, self.marker),
+        }
+    }
+
+    /// Resolve colour conflicts within a cell by merging them in place instead of requiring an
+    /// explicit [`Context::layer`] call between differently coloured shapes.
+    ///
+    /// This only affects the [`Marker::Braille`] grid, which otherwise only keeps the last colour
+    /// painted per 2x4 dot cell. With `merge_colors(true)`, painting a dot with a different colour
+    /// than the one already stored for that cell clears the cell's previously accumulated dots
+    /// before drawing the new one, so the most recently drawn shape wins the whole cell. Dots
+    /// painted with [`Color::Reset`] never clear a cell or replace its colour. Otherwise this
+    /// produces output equivalent to separating the shapes with `ctx.layer()`, but in a single
+    /// pass over the grid with one string allocation instead of one per layer.
+    ///
+    /// [`Marker::Braille`]: symbols::Marker::Braille
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, widgets::canvas::*};
+    ///
+    /// Canvas::default().merge_colors(true).paint(|ctx| {});
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn merge_colors(mut self, merge_colors: bool) -> Canvas<'a, F> {
+        self.merge_colors = merge_colors;
         self
     }
 }
@@ -717,60 +888,148 @@ where
         };
 
         // Create a blank context that match the size of the canvas
-        let mut ctx = Context::new(
-            canvas_area.width,
-            canvas_area.height,
-            self.x_bounds,
-            self.y_bounds,
-            self.marker,
-        );
+        let grid = self.new_grid(canvas_area.width, canvas_area.height);
+        let mut ctx = Context::with_grid(grid, self.x_bounds, self.y_bounds);
+        ctx.grid.set_merge_colors(self.merge_colors);
         // Paint to this context
         painter(&mut ctx);
         ctx.finish();
 
         // Retrieve painted points for each layer
-        for layer in ctx.layers {
-            for (index, (ch, colors)) in layer.string.chars().zip(layer.colors).enumerate() {
-                if ch != ' ' && ch != '\u{2800}' {
-                    let (x, y) = (
-                        (index % width) as u16 + canvas_area.left(),
-                        (index / width) as u16 + canvas_area.top(),
-                    );
-                    let cell = buf.get_mut(x, y).set_char(ch);
-                    if colors.0 != Color::Reset {
-                        cell.set_fg(colors.0);
-                    }
-                    if colors.1 != Color::Reset {
-                        cell.set_bg(colors.1);
-                    }
-                }
-            }
-        }
+        render_layers(ctx.layers, width, canvas_area, buf);
 
         // Finally draw the labels
-        let left = self.x_bounds[0];
-        let right = self.x_bounds[1];
-        let top = self.y_bounds[1];
-        let bottom = self.y_bounds[0];
-        let width = (self.x_bounds[1] - self.x_bounds[0]).abs();
-        let height = (self.y_bounds[1] - self.y_bounds[0]).abs();
-        let resolution = {
-            let width = f64::from(canvas_area.width - 1);
-            let height = f64::from(canvas_area.height - 1);
-            (width, height)
+        render_labels(&ctx.labels, self.x_bounds, self.y_bounds, canvas_area, buf);
+    }
+}
+
+/// State that lets a [`Canvas`] reuse its grid and layer buffers across renders instead of
+/// allocating a fresh [`Context`] every frame.
+///
+/// Render with [`StatefulWidget::render`] instead of [`Widget::render`] when the same canvas is
+/// redrawn repeatedly, e.g. at an interactive frame rate. The grid is only reallocated when the
+/// rendered area, [`Canvas::marker`], or [`Canvas::custom_marker`] changes between frames;
+/// otherwise the existing buffers are reset in place via [`Grid::reset`].
+#[derive(Debug, Default)]
+pub struct CanvasState {
+    grid: Option<Box<dyn Grid>>,
+    area: Rect,
+    marker: Option<symbols::Marker>,
+    custom_marker: Option<char>,
+}
+
+impl CanvasState {
+    /// Creates a new, empty [`CanvasState`].
+    ///
+    /// The first render always allocates a fresh grid; later renders reuse it as long as the area
+    /// and marker stay the same.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<'a, F> StatefulWidget for Canvas<'a, F>
+where
+    F: Fn(&mut Context),
+{
+    type State = CanvasState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut CanvasState) {
+        let canvas_area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
         };
-        for label in ctx
-            .labels
-            .iter()
-            .filter(|l| l.x >= left && l.x <= right && l.y <= top && l.y >= bottom)
-        {
-            let x = ((label.x - left) * resolution.0 / width) as u16 + canvas_area.left();
-            let y = ((top - label.y) * resolution.1 / height) as u16 + canvas_area.top();
-            buf.set_line(x, y, &label.line, canvas_area.right() - x);
+
+        buf.set_style(canvas_area, Style::default().bg(self.background_color));
+
+        let width = canvas_area.width as usize;
+
+        let Some(ref painter) = self.paint_func else {
+            return;
+        };
+
+        let reuse = state.grid.is_some()
+            && state.area == canvas_area
+            && state.marker == Some(self.marker)
+            && state.custom_marker == self.custom_marker;
+        let grid = if reuse {
+            let mut grid = state.grid.take().expect("checked by `reuse` above");
+            grid.reset();
+            grid
+        } else {
+            self.new_grid(canvas_area.width, canvas_area.height)
+        };
+        state.area = canvas_area;
+        state.marker = Some(self.marker);
+        state.custom_marker = self.custom_marker;
+
+        let mut ctx = Context::with_grid(grid, self.x_bounds, self.y_bounds);
+        ctx.grid.set_merge_colors(self.merge_colors);
+        painter(&mut ctx);
+        ctx.finish();
+
+        render_layers(ctx.layers, width, canvas_area, buf);
+        render_labels(&ctx.labels, self.x_bounds, self.y_bounds, canvas_area, buf);
+
+        // Hand the grid back so the next render can reuse its buffers.
+        state.grid = Some(ctx.grid);
+    }
+}
+
+/// Blit every non-empty cell of each painted layer onto the buffer.
+fn render_layers(layers: Vec<Layer>, width: usize, area: Rect, buf: &mut Buffer) {
+    for layer in layers {
+        for (index, (ch, colors)) in layer.string.chars().zip(layer.colors).enumerate() {
+            if ch != ' ' && ch != '\u{2800}' {
+                let (x, y) = (
+                    (index % width) as u16 + area.left(),
+                    (index / width) as u16 + area.top(),
+                );
+                let cell = buf.get_mut(x, y).set_char(ch);
+                if colors.0 != Color::Reset {
+                    cell.set_style(Style::default().fg(colors.0));
+                }
+                if colors.1 != Color::Reset {
+                    cell.set_style(Style::default().bg(colors.1));
+                }
+            }
         }
     }
 }
 
+/// Draw the labels printed via [`Context::print`] on top of the painted layers.
+fn render_labels(
+    labels: &[Label<'_>],
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+    area: Rect,
+    buf: &mut Buffer,
+) {
+    let left = x_bounds[0];
+    let right = x_bounds[1];
+    let top = y_bounds[1];
+    let bottom = y_bounds[0];
+    let width = (x_bounds[1] - x_bounds[0]).abs();
+    let height = (y_bounds[1] - y_bounds[0]).abs();
+    let resolution = {
+        let width = f64::from(area.width - 1);
+        let height = f64::from(area.height - 1);
+        (width, height)
+    };
+    for label in labels
+        .iter()
+        .filter(|l| l.x >= left && l.x <= right && l.y <= top && l.y >= bottom)
+    {
+        let x = ((label.x - left) * resolution.0 / width) as u16 + area.left();
+        let y = ((top - label.y) * resolution.1 / height) as u16 + area.top();
+        buf.set_line(x, y, &label.line, area.right() - x);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
@@ -856,6 +1115,238 @@ mod tests {
         );
     }
 
+    #[test]
+    fn braille_grid_merge_colors_overwrites_cell_dots_on_color_change() {
+        let mut merged = BrailleGrid::new(1, 1);
+        merged.set_merge_colors(true);
+        merged.paint(0, 0, Color::Red);
+        merged.paint(1, 2, Color::Red); // same color as before, dots accumulate
+        merged.paint(0, 1, Color::Blue); // different color, clears the cell first
+
+        let mut only_last_dot = BrailleGrid::new(1, 1);
+        only_last_dot.paint(0, 1, Color::Blue);
+
+        assert_eq!(merged.utf16_code_points, only_last_dot.utf16_code_points);
+        assert_eq!(merged.colors, vec![Color::Blue]);
+    }
+
+    #[test]
+    fn braille_grid_merge_colors_lets_uncolored_dots_share_a_cell_with_a_colored_one() {
+        let mut merged = BrailleGrid::new(1, 1);
+        merged.set_merge_colors(true);
+        merged.paint(0, 0, Color::Red);
+        merged.paint(1, 2, Color::Reset); // uncolored dot, must not clear the red dot
+
+        let mut both_dots_red = BrailleGrid::new(1, 1);
+        both_dots_red.paint(0, 0, Color::Red);
+        both_dots_red.paint(1, 2, Color::Red);
+
+        assert_eq!(merged.utf16_code_points, both_dots_red.utf16_code_points);
+        assert_eq!(merged.colors, vec![Color::Red]);
+    }
+
+    #[test]
+    fn braille_grid_paint_background_tints_the_whole_cell() {
+        let mut grid = BrailleGrid::new(2, 1);
+        grid.paint(0, 0, Color::Red);
+        grid.paint_background(1, 2, Color::Blue);
+
+        let layer = grid.save();
+        assert_eq!(layer.colors[0], (Color::Red, Color::Blue));
+        // the second cell was never painted, its background stays reset
+        assert_eq!(layer.colors[1], (Color::Reset, Color::Reset));
+    }
+
+    #[test]
+    fn braille_grid_reset_clears_the_background_color() {
+        let mut grid = BrailleGrid::new(1, 1);
+        grid.paint_background(0, 0, Color::Blue);
+        grid.reset();
+
+        assert_eq!(grid.save().colors[0], (Color::Reset, Color::Reset));
+    }
+
+    #[test]
+    fn braille_grid_without_merge_colors_accumulates_dots_across_colors() {
+        let mut grid = BrailleGrid::new(1, 1);
+        grid.paint(0, 0, Color::Red);
+        grid.paint(0, 1, Color::Blue);
+
+        let mut all_dots = BrailleGrid::new(1, 1);
+        all_dots.paint(0, 0, Color::Red);
+        all_dots.paint(0, 1, Color::Red);
+
+        assert_eq!(grid.utf16_code_points, all_dots.utf16_code_points);
+        assert_eq!(grid.colors, vec![Color::Blue]);
+    }
+
+    #[test]
+    fn stateful_canvas_matches_stateless_render() {
+        let area = Rect::new(0, 0, 5, 5);
+
+        let mut stateless_buf = Buffer::empty(area);
+        Canvas::default()
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 10.0])
+            .paint(|ctx| {
+                ctx.draw(&Line {
+                    x1: 0.0,
+                    y1: 0.0,
+                    x2: 10.0,
+                    y2: 0.0,
+                    color: Color::Reset,
+                });
+            })
+            .render(area, &mut stateless_buf);
+
+        let mut stateful_buf = Buffer::empty(area);
+        let mut state = CanvasState::new();
+        StatefulWidget::render(
+            Canvas::default()
+                .x_bounds([0.0, 10.0])
+                .y_bounds([0.0, 10.0])
+                .paint(|ctx| {
+                    ctx.draw(&Line {
+                        x1: 0.0,
+                        y1: 0.0,
+                        x2: 10.0,
+                        y2: 0.0,
+                        color: Color::Reset,
+                    });
+                }),
+            area,
+            &mut stateful_buf,
+            &mut state,
+        );
+
+        assert_eq!(stateless_buf, stateful_buf);
+    }
+
+    #[test]
+    fn stateful_canvas_resets_the_reused_grid_between_frames() {
+        let area = Rect::new(0, 0, 5, 5);
+        let mut buf = Buffer::empty(area);
+        let mut state = CanvasState::new();
+
+        StatefulWidget::render(
+            Canvas::default()
+                .x_bounds([0.0, 10.0])
+                .y_bounds([0.0, 10.0])
+                .paint(|ctx| {
+                    ctx.draw(&Line {
+                        x1: 0.0,
+                        y1: 0.0,
+                        x2: 10.0,
+                        y2: 0.0,
+                        color: Color::Reset,
+                    });
+                }),
+            area,
+            &mut buf,
+            &mut state,
+        );
+        // second frame draws nothing; any dots left over from the first frame's grid must be
+        // cleared rather than shown through
+        StatefulWidget::render(
+            Canvas::default()
+                .x_bounds([0.0, 10.0])
+                .y_bounds([0.0, 10.0])
+                .paint(|_ctx| {}),
+            area,
+            &mut buf,
+            &mut state,
+        );
+
+        let mut expected = Buffer::empty(area);
+        Canvas::default()
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 10.0])
+            .paint(|_ctx| {})
+            .render(area, &mut expected);
+
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn stateful_canvas_reallocates_the_grid_when_the_area_changes() {
+        let mut state = CanvasState::new();
+        let mut small_buf = Buffer::empty(Rect::new(0, 0, 5, 5));
+        StatefulWidget::render(
+            Canvas::default().paint(|_ctx| {}),
+            Rect::new(0, 0, 5, 5),
+            &mut small_buf,
+            &mut state,
+        );
+        assert_eq!(state.area, Rect::new(0, 0, 5, 5));
+
+        let mut large_buf = Buffer::empty(Rect::new(0, 0, 8, 8));
+        StatefulWidget::render(
+            Canvas::default().paint(|_ctx| {}),
+            Rect::new(0, 0, 8, 8),
+            &mut large_buf,
+            &mut state,
+        );
+        assert_eq!(state.area, Rect::new(0, 0, 8, 8));
+    }
+
+    #[test]
+    fn canvas_merge_colors_defaults_to_false() {
+        let canvas = Canvas::default().paint(|_ctx| {});
+        assert!(!canvas.merge_colors);
+    }
+
+    #[test]
+    fn test_custom_marker() {
+        let area = Rect::new(0, 0, 5, 5);
+        let mut cell = Cell::default();
+        cell.set_char('x');
+        let mut buf = Buffer::filled(area, &cell);
+        Canvas::default()
+            .custom_marker('+')
+            .paint(|ctx| {
+                ctx.draw(&Line {
+                    x1: 0.0,
+                    y1: 0.0,
+                    x2: 0.0,
+                    y2: 10.0,
+                    color: Color::Reset,
+                });
+            })
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 10.0])
+            .render(area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(
+                indoc!(
+                    "
+                    +xxxx
+                    +xxxx
+                    +xxxx
+                    +xxxx
+                    +xxxx"
+                )
+                .lines()
+                .collect()
+            )
+        );
+    }
+
+    #[test]
+    fn custom_marker_takes_priority_over_marker_and_is_cleared_by_marker() {
+        let with_custom = Canvas::default()
+            .marker(Marker::Braille)
+            .custom_marker('+')
+            .paint(|_ctx| {});
+        assert_eq!(with_custom.custom_marker, Some('+'));
+
+        let reset_by_marker = Canvas::default()
+            .custom_marker('+')
+            .marker(Marker::Braille)
+            .paint(|_ctx| {});
+        assert_eq!(reset_by_marker.custom_marker, None);
+    }
+
     #[test]
     fn test_dot_marker() {
         test_marker(
@@ -0,0 +1,121 @@
+use crate::{
+    style::Color,
+    widgets::canvas::{Painter, Shape},
+};
+
+/// A rectangle filled solid with a single color.
+///
+/// Unlike [`Rectangle`](super::Rectangle), which only strokes the outline, `FilledRectangle`
+/// paints every dot inside its bounds. This is useful for flamegraph rows, stacked bars, or any
+/// other shape that needs a solid region rather than just an outline. It composes with
+/// [`Context::print`](super::Context::print) for labelling the bars.
+///
+/// `x` and `y` are the bottom left corner of the rectangle, with `width` and `height` extending up
+/// and to the right, using the same coordinate system as the canvas's `x_bounds`/`y_bounds`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilledRectangle {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    pub color: Color,
+}
+
+impl Shape for FilledRectangle {
+    fn draw(&self, painter: &mut Painter) {
+        // Clip to the canvas's bounds first, the same way Chart's line/area/bar graph types clip
+        // against axis bounds, so a rectangle that's only partially in view (e.g. a flamegraph row
+        // scrolled halfway off screen) is drawn up to the edge instead of vanishing entirely.
+        let [left, right] = painter.context.x_bounds;
+        let [bottom, top] = painter.context.y_bounds;
+        let x_min = self.x.max(left);
+        let x_max = (self.x + self.width).min(right);
+        let y_min = self.y.max(bottom);
+        let y_max = (self.y + self.height).min(top);
+        if x_min > x_max || y_min > y_max {
+            return;
+        }
+        let Some((x0, y0)) = painter.get_point(x_min, y_min) else {
+            return;
+        };
+        let Some((x1, y1)) = painter.get_point(x_max, y_max) else {
+            return;
+        };
+        let (x_min, x_max) = if x0 <= x1 { (x0, x1) } else { (x1, x0) };
+        let (y_min, y_max) = if y0 <= y1 { (y0, y1) } else { (y1, y0) };
+        for y in y_min..=y_max {
+            for x in x_min..=x_max {
+                painter.paint(x, y, self.color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{symbols::Marker, widgets::canvas::Context};
+
+    #[test]
+    fn draw_paints_every_dot_between_the_two_opposite_corners() {
+        // x_bounds/y_bounds are chosen so get_point maps 1:1 onto grid dots, which makes the
+        // expected painted indices easy to compute by hand.
+        let mut ctx = Context::new(4, 4, [0.0, 3.0], [0.0, 3.0], Marker::Block);
+        ctx.draw(&FilledRectangle {
+            x: 1.0,
+            y: 1.0,
+            width: 1.0,
+            height: 1.0,
+            color: Color::Red,
+        });
+        let layer = ctx.grid.save();
+        let painted: Vec<usize> = layer
+            .colors
+            .iter()
+            .enumerate()
+            .filter(|(_, (fg, _))| *fg == Color::Red)
+            .map(|(index, _)| index)
+            .collect();
+        // dots (1, 1), (2, 1), (1, 2), (2, 2) in (x, y) grid coordinates, index = y * width + x
+        assert_eq!(painted, vec![5, 6, 9, 10]);
+    }
+
+    #[test]
+    fn draw_clips_to_bounds_when_partially_out_of_view() {
+        // x_bounds/y_bounds are chosen so get_point maps 1:1 onto grid dots, which makes the
+        // expected painted indices easy to compute by hand.
+        let mut ctx = Context::new(4, 4, [0.0, 3.0], [0.0, 3.0], Marker::Block);
+        ctx.draw(&FilledRectangle {
+            x: 2.0,
+            y: 2.0,
+            width: 5.0,
+            height: 5.0,
+            color: Color::Red,
+        });
+        let layer = ctx.grid.save();
+        let painted: Vec<usize> = layer
+            .colors
+            .iter()
+            .enumerate()
+            .filter(|(_, (fg, _))| *fg == Color::Red)
+            .map(|(index, _)| index)
+            .collect();
+        // the rectangle extends from (2, 2) to (7, 7), clipped down to (2, 2)-(3, 3) in canvas
+        // coordinates, i.e. grid dots (2, 0), (3, 0), (2, 1), (3, 1), index = y * width + x
+        assert_eq!(painted, vec![2, 3, 6, 7]);
+    }
+
+    #[test]
+    fn draw_skips_painting_when_fully_out_of_bounds() {
+        let mut ctx = Context::new(4, 4, [0.0, 3.0], [0.0, 3.0], Marker::Block);
+        ctx.draw(&FilledRectangle {
+            x: 10.0,
+            y: 10.0,
+            width: 1.0,
+            height: 1.0,
+            color: Color::Red,
+        });
+        let layer = ctx.grid.save();
+        assert!(layer.colors.iter().all(|(fg, _)| *fg != Color::Red));
+    }
+}
@@ -1,5 +1,5 @@
 #![warn(missing_docs)]
-use std::cmp::max;
+use std::{cmp::max, collections::VecDeque, fmt};
 
 use strum::{Display, EnumString};
 use unicode_width::UnicodeWidthStr;
@@ -47,6 +47,22 @@ pub struct Axis<'a> {
     style: Style,
     /// The alignment of the labels of the Axis
     labels_alignment: Alignment,
+    /// The scale used to map data values onto the axis
+    scale: AxisScale,
+    /// Whether to thin out labels (or hide them entirely) rather than let them overlap
+    labels_thinning: bool,
+    /// Number of evenly spaced labels to auto-generate from `bounds` when `labels` isn't set
+    auto_labels: Option<usize>,
+    /// Decimal precision used when formatting auto-generated labels
+    label_precision: usize,
+    /// Whether `bounds` should be derived from the chart's dataset points instead of the
+    /// explicit value set via [`Axis::bounds`]
+    auto_bounds: bool,
+    /// Fraction of the derived span to pad auto-computed bounds by on each side
+    bounds_padding: f64,
+    /// `now` set by [`Axis::time_window`], used to format [`Axis::auto_labels`] as durations
+    /// relative to `now` instead of plain numbers
+    time_window: Option<f64>,
 }
 
 impl<'a> Axis<'a> {
@@ -138,6 +154,206 @@ impl<'a> Axis<'a> {
         self.labels_alignment = alignment;
         self
     }
+
+    /// Sets the scale used to map data values onto the axis
+    ///
+    /// Defaults to [`AxisScale::Linear`]. Use [`AxisScale::Log`] for datasets spanning several
+    /// orders of magnitude, such as network throughput.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let axis = Axis::default().bounds([1.0, 1_000_000.0]).scale(AxisScale::Log);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn scale(mut self, scale: AxisScale) -> Axis<'a> {
+        self.scale = scale;
+        self
+    }
+
+    /// Enables automatic thinning of this axis's labels to fit the available width
+    ///
+    /// This is already the default behavior for the x-axis via [`Chart::x_labels_autohide`];
+    /// setting it here opts this axis in regardless of that chart-wide setting. Without either,
+    /// [`Axis::labels`] are spread evenly across the axis regardless of how many there are or how
+    /// wide they are, which overlaps and mis-positions labels once there are more than a handful
+    /// (see [issue 334]). When thinning is active, the chart instead measures each label's
+    /// display width and drops every Nth label so the survivors stay evenly spaced and
+    /// collision-free; if the area is too narrow for even two labels, the whole axis label row is
+    /// hidden (and its space given back to the graph) instead of overlapping.
+    ///
+    /// [issue 334]: https://github.com/ratatui-org/ratatui/issues/334
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn labels_thinning(mut self, labels_thinning: bool) -> Axis<'a> {
+        self.labels_thinning = labels_thinning;
+        self
+    }
+
+    /// Auto-generates `count` evenly spaced labels between `bounds[0]` and `bounds[1]`
+    /// (inclusive of both ends) instead of requiring an explicit [`Axis::labels`] vector.
+    ///
+    /// Formatting defaults to `0` decimal places; use [`Axis::label_precision`] to change that.
+    /// Has no effect if [`Axis::labels`] is also set -- an explicit label vector always wins.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn auto_labels(mut self, count: usize) -> Axis<'a> {
+        self.auto_labels = Some(count);
+        self
+    }
+
+    /// Sets the number of decimal places used when formatting labels generated by
+    /// [`Axis::auto_labels`]. Defaults to `0`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label_precision(mut self, precision: usize) -> Axis<'a> {
+        self.label_precision = precision;
+        self
+    }
+
+    /// Derives this axis's bounds from the chart's dataset points instead of the value set via
+    /// [`Axis::bounds`].
+    ///
+    /// The chart scans every [`Dataset`] point, ignoring any coordinate that isn't
+    /// [`f64::is_finite`] (`NaN`/`±inf`, which a fixed-window average or a division can produce),
+    /// and uses the resulting `[min, max]` as this axis's bounds. If none of the data has a
+    /// finite coordinate on this axis, the bounds fall back to `[0.0, 0.0]` rather than spanning
+    /// `NaN`. Use [`Axis::bounds_padding`] to keep extreme points off the chart's edge.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn auto_bounds(mut self) -> Axis<'a> {
+        self.auto_bounds = true;
+        self
+    }
+
+    /// Pads bounds derived by [`Axis::auto_bounds`] by `fraction` of their span on each side, so
+    /// the extreme points aren't drawn exactly on the graph's border. Has no effect unless
+    /// [`Axis::auto_bounds`] is also set. Defaults to `0.0`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn bounds_padding(mut self, fraction: f64) -> Axis<'a> {
+        self.bounds_padding = fraction;
+        self
+    }
+
+    /// Sets this axis's bounds to `[now - window, now]`, for the common "rolling live graph" use
+    /// case of plotting the last `window` seconds (or whatever time unit the data uses) up to
+    /// the current time.
+    ///
+    /// Combine with [`Axis::auto_labels`] to also generate tick labels, which this mode formats
+    /// as durations relative to `now` (e.g. `-10s`, `-5s`, `0s`) instead of plain numbers.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn time_window(mut self, now: f64, window: f64) -> Axis<'a> {
+        self.bounds = [now - window, now];
+        self.time_window = Some(now);
+        self
+    }
+
+    /// Returns the axis's explicit [`Axis::labels`] if set, otherwise the labels generated by
+    /// [`Axis::auto_labels`] (or `None` if neither is set).
+    fn resolved_labels(&self) -> Option<Vec<Span<'a>>> {
+        if self.labels.is_some() {
+            return self.labels.clone();
+        }
+        let count = self.auto_labels?;
+        if count == 0 {
+            return Some(Vec::new());
+        }
+        let format_value = |value: f64| match self.time_window {
+            Some(now) => format_relative_time(value, now, self.label_precision),
+            None => format!("{value:.*}", self.label_precision),
+        };
+        if count == 1 {
+            return Some(vec![Span::from(format_value(self.bounds[0]))]);
+        }
+        Some(
+            (0..count)
+                .map(|i| {
+                    let t = i as f64 / (count - 1) as f64;
+                    let value = self.bounds[0] + t * (self.bounds[1] - self.bounds[0]);
+                    Span::from(format_value(value))
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Formats `value` as a duration relative to `now` (e.g. `-10s`, `-5s`, `0s`), for
+/// [`Axis::time_window`]'s auto-generated labels.
+fn format_relative_time(value: f64, now: f64, precision: usize) -> String {
+    let delta = value - now;
+    if delta == 0.0 {
+        format!("{:.*}s", precision, 0.0)
+    } else {
+        format!("{delta:+.*}s", precision)
+    }
+}
+
+/// Used to determine the scale used to map data values onto an [`Axis`]
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum AxisScale {
+    /// Data values are mapped onto the axis proportionally to their distance from the bounds.
+    #[default]
+    Linear,
+    /// Data values are mapped onto the axis proportionally to the distance between their base-10
+    /// logarithms, so that values spanning several orders of magnitude remain legible.
+    ///
+    /// Nonpositive values, and a nonpositive lower bound, are clamped to the smallest positive
+    /// `f64` rather than producing `NaN`/`-inf`.
+    Log,
+}
+
+/// Maps `bounds` into the space the axis actually plots in, applying `scale`'s transform.
+fn scaled_bounds(bounds: [f64; 2], scale: AxisScale) -> [f64; 2] {
+    [
+        scaled_value(bounds[0], scale),
+        scaled_value(bounds[1], scale),
+    ]
+}
+
+/// Folds `values` into a `[min, max]` pair, ignoring any value that isn't
+/// [`f64::is_finite`] so a stray `NaN`/`±inf` can't poison the whole range via the usual
+/// `f64::min`/`max` propagation. Returns `[0.0, 0.0]` if no finite value is found.
+fn finite_bounds(values: impl Iterator<Item = f64>) -> [f64; 2] {
+    let (min, max) = values
+        .filter(|v| v.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+            (min.min(v), max.max(v))
+        });
+    if min.is_finite() && max.is_finite() {
+        [min, max]
+    } else {
+        [0.0, 0.0]
+    }
+}
+
+/// Pads `bounds` by `fraction` of their span on each side. A non-positive `fraction` (the
+/// default) leaves `bounds` untouched.
+fn pad_bounds(bounds: [f64; 2], fraction: f64) -> [f64; 2] {
+    if fraction <= 0.0 {
+        return bounds;
+    }
+    let pad = (bounds[1] - bounds[0]) * fraction;
+    [bounds[0] - pad, bounds[1] + pad]
+}
+
+/// Maps a single data `value` into the space the axis actually plots in, applying `scale`'s
+/// transform.
+fn scaled_value(value: f64, scale: AxisScale) -> f64 {
+    match scale {
+        AxisScale::Linear => value,
+        AxisScale::Log => value.max(f64::MIN_POSITIVE).log10(),
+    }
 }
 
 /// Used to determine which style of graphing to use
@@ -151,6 +367,24 @@ pub enum GraphType {
     /// The order of the lines will be the same as the order of the points in the dataset, which
     /// allows this widget to draw lines both left-to-right and right-to-left
     Line,
+    /// Draw a vertical run of marker cells from the axis baseline up to each point's y value.
+    ///
+    /// The baseline is `0` clamped to the axis bounds, so a dataset whose bounds don't include
+    /// `0` still gets a bar anchored to the nearest edge of the visible area.
+    Bar,
+    /// Draw a line between each following point, like [`GraphType::Line`], and additionally fill
+    /// the region between the line and the axis baseline (`0` clamped to the axis bounds).
+    ///
+    /// This gives the common "area chart" look for a single dataset without having to stack a
+    /// second [`GraphType::Bar`] dataset underneath it.
+    Area,
+    /// Draw a "staircase" between each following point: a horizontal segment at the earlier
+    /// point's y value out to the next point's x, then a vertical segment up (or down) to the
+    /// next point's y value.
+    ///
+    /// This avoids the misleading linear interpolation of [`GraphType::Line`] for data that is
+    /// only known at the sample points, like connection counts or other discrete gauges.
+    Step,
 }
 
 /// Allow users to specify the position of a legend in a [`Chart`]
@@ -277,9 +511,10 @@ impl LegendPosition {
 ///
 /// A dataset can be [named](Dataset::name). Only named datasets will be rendered in the legend.
 ///
-/// After that, you can pass it data with [`Dataset::data`]. Data is an array of `f64` tuples
-/// (`(f64, f64)`), the first element being X and the second Y. It's also worth noting that, unlike
-/// the [`Rect`], here the Y axis is bottom to top, as in math.
+/// After that, you can pass it data with [`Dataset::data`]. Data is anything implementing
+/// [`GraphData`] -- a slice, array, or `Vec` of `(f64, f64)` tuples out of the box, the first
+/// element being X and the second Y -- or your own type for streaming/ring-buffer sources. It's
+/// also worth noting that, unlike the [`Rect`], here the Y axis is bottom to top, as in math.
 ///
 /// You can also customize the rendering by using [`Dataset::marker`] and [`Dataset::graph_type`].
 ///
@@ -297,18 +532,170 @@ impl LegendPosition {
 ///     .graph_type(GraphType::Line)
 ///     .red();
 /// ```
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Dataset<'a> {
     /// Name of the dataset (used in the legend if shown)
     name: Option<Line<'a>>,
     /// A reference to the actual data
-    data: &'a [(f64, f64)],
+    data: &'a dyn GraphData,
     /// Symbol used for each points of this dataset
     marker: symbols::Marker,
     /// Determines graph type used for drawing points
     graph_type: GraphType,
     /// Style used to plot this dataset
     style: Style,
+    /// Whether the data is known to be ascending in x, enabling binary-search culling
+    sorted_by_x: bool,
+}
+
+impl<'a> Default for Dataset<'a> {
+    fn default() -> Self {
+        const EMPTY: &[(f64, f64)] = &[];
+        Self {
+            name: None,
+            data: EMPTY,
+            marker: symbols::Marker::default(),
+            graph_type: GraphType::default(),
+            style: Style::default(),
+            sorted_by_x: false,
+        }
+    }
+}
+
+impl<'a> PartialEq for Dataset<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.marker == other.marker
+            && self.graph_type == other.graph_type
+            && self.style == other.style
+            && self.sorted_by_x == other.sorted_by_x
+            && self.data.len() == other.data.len()
+            && self.data.iter().eq(other.data.iter())
+    }
+}
+
+/// A source of the `(f64, f64)` points a [`Dataset`] plots.
+///
+/// [`Dataset::data`] accepts anything implementing this trait, which lets live-updating charts
+/// feed a ring buffer or other non-contiguous store directly instead of materializing a
+/// contiguous `&[(f64, f64)]` slice on every frame.
+pub trait GraphData {
+    /// Returns the number of points in the data source.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the data source has no points.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterates over every point in the data source, in order.
+    fn iter(&self) -> Box<dyn Iterator<Item = (f64, f64)> + '_>;
+
+    /// Iterates over only the points whose `x` falls within `x_bounds`.
+    ///
+    /// The default implementation filters [`GraphData::iter`]. A data source backed by an
+    /// x-ordered store (e.g. a ring buffer of samples) can override this to binary-search the
+    /// visible window instead of scanning every point.
+    fn in_bounds(&self, x_bounds: [f64; 2]) -> Box<dyn Iterator<Item = (f64, f64)> + '_> {
+        Box::new(
+            self.iter()
+                .filter(move |&(x, _)| x >= x_bounds[0] && x <= x_bounds[1]),
+        )
+    }
+}
+
+impl fmt::Debug for dyn GraphData + '_ {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl GraphData for [(f64, f64)] {
+    fn len(&self) -> usize {
+        <[(f64, f64)]>::len(self)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (f64, f64)> + '_> {
+        Box::new(<[(f64, f64)]>::iter(self).copied())
+    }
+}
+
+impl<const N: usize> GraphData for [(f64, f64); N] {
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (f64, f64)> + '_> {
+        Box::new(self.as_slice().iter().copied())
+    }
+}
+
+impl GraphData for Vec<(f64, f64)> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (f64, f64)> + '_> {
+        Box::new(self.as_slice().iter().copied())
+    }
+}
+
+/// A fixed-capacity ring buffer of `(timestamp, value)` points for a rolling live [`Dataset`].
+///
+/// This packages the "rolling `VecDeque` with a fixed display duration" pattern used by
+/// diagnostic TUIs (e.g. a live CPU or network graph): push samples as they arrive via
+/// [`TimeSeries::push`], call [`TimeSeries::retain_window`] once per frame to drop points that
+/// have scrolled out of the display window, and pair it with [`Axis::time_window`] on the chart's
+/// x-axis to keep the bounds and labels following `now` without re-deriving them by hand.
+///
+/// Points are expected to be pushed in non-decreasing timestamp order, so a [`Dataset`] backed by
+/// a `TimeSeries` should set [`Dataset::sorted_by_x`] to take advantage of binary-search culling.
+#[derive(Debug, Clone)]
+pub struct TimeSeries {
+    points: VecDeque<(f64, f64)>,
+    capacity: usize,
+}
+
+impl TimeSeries {
+    /// Creates an empty time series that holds at most `capacity` points, dropping the oldest
+    /// point once a push would exceed it.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            points: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Pushes a new `(timestamp, value)` point, dropping the oldest point first if the series is
+    /// already at capacity.
+    pub fn push(&mut self, timestamp: f64, value: f64) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.points.len() >= self.capacity {
+            self.points.pop_front();
+        }
+        self.points.push_back((timestamp, value));
+    }
+
+    /// Drops every point older than `now - window`, the oldest edge of the current display
+    /// window, so the series doesn't keep rendering samples that have scrolled out of view.
+    pub fn retain_window(&mut self, now: f64, window: f64) {
+        let cutoff = now - window;
+        while matches!(self.points.front(), Some(&(timestamp, _)) if timestamp < cutoff) {
+            self.points.pop_front();
+        }
+    }
+}
+
+impl GraphData for TimeSeries {
+    fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (f64, f64)> + '_> {
+        Box::new(self.points.iter().copied())
+    }
 }
 
 impl<'a> Dataset<'a> {
@@ -336,13 +723,19 @@ impl<'a> Dataset<'a> {
     /// Points will then either be rendered as scrattered points or with lines between them
     /// depending on [`Dataset::graph_type`].
     ///
-    /// Data consist in an array of `f64` tuples (`(f64, f64)`), the first element being X and the
-    /// second Y. It's also worth noting that, unlike the [`Rect`], here the Y axis is bottom to
-    /// top, as in math.
+    /// Accepts anything implementing [`GraphData`] -- a slice, array, or `Vec` of `f64` tuples
+    /// (`(f64, f64)`) out of the box, the first element being X and the second Y. It's also worth
+    /// noting that, unlike the [`Rect`], here the Y axis is bottom to top, as in math.
+    ///
+    /// Implementing [`GraphData`] on your own type lets a live-updating chart feed a ring buffer
+    /// or other rolling sample store directly, without rebuilding a contiguous slice every frame.
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn data(mut self, data: &'a [(f64, f64)]) -> Dataset<'a> {
+    pub fn data<T>(mut self, data: &'a T) -> Dataset<'a>
+    where
+        T: GraphData + ?Sized,
+    {
         self.data = data;
         self
     }
@@ -375,6 +768,19 @@ impl<'a> Dataset<'a> {
         self
     }
 
+    /// Asserts that the data is ascending in x, so the chart can binary-search for the visible
+    /// range instead of testing every point against the bounds.
+    ///
+    /// Only set this to `true` if the data really is sorted; if it isn't, the visible range found
+    /// by the search may be wrong and points can silently go missing from the render.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn sorted_by_x(mut self, sorted_by_x: bool) -> Dataset<'a> {
+        self.sorted_by_x = sorted_by_x;
+        self
+    }
+
     /// Sets the style of this dataset
     ///
     /// The given style will be used to draw the legend and the data points. Currently the legend
@@ -419,6 +825,12 @@ struct ChartLayout {
     axis_y: Option<u16>,
     /// Area of the legend
     legend_area: Option<Rect>,
+    /// Number of columns the legend's dataset entries are wrapped into, when `legend_area` is
+    /// `Some`
+    legend_columns: u16,
+    /// Width, including the marker swatch, reserved for each legend column, when `legend_area`
+    /// is `Some`
+    legend_column_width: u16,
     /// Area of the graph
     graph_area: Rect,
 }
@@ -500,9 +912,18 @@ pub struct Chart<'a> {
     /// The position detnermine where the legenth is shown or hide regaurdless of
     /// `hidden_legend_constraints`
     legend_position: Option<LegendPosition>,
+    /// Whether x-axis labels that don't fit the available width are automatically decimated
+    x_labels_autohide: bool,
+    /// Number of columns to wrap the legend's dataset entries into. `None` auto-fits as many
+    /// columns as the legend's width budget allows.
+    legend_columns: Option<u16>,
 }
 
 impl<'a> Chart<'a> {
+    /// Width, in columns, of a legend row's marker swatch plus the space separating it from the
+    /// dataset name.
+    const LEGEND_SWATCH_WIDTH: u16 = 2;
+
     /// Creates a chart with the given [datasets](Dataset)
     ///
     /// A chart can render multiple datasets.
@@ -537,6 +958,8 @@ impl<'a> Chart<'a> {
             datasets,
             hidden_legend_constraints: (Constraint::Ratio(1, 4), Constraint::Ratio(1, 4)),
             legend_position: Some(LegendPosition::default()),
+            x_labels_autohide: true,
+            legend_columns: None,
         }
     }
 
@@ -609,6 +1032,20 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Sets whether x-axis labels wider than their slot are automatically decimated to a subset
+    /// that fits, rather than left to overlap. Enabled by default.
+    ///
+    /// This is the chart-wide counterpart to [`Axis::labels_thinning`], which opts a single axis
+    /// into the same behavior; the two can be combined freely, since a `Chart` with autohide off
+    /// still respects an axis that explicitly asked for thinning.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn x_labels_autohide(mut self, autohide: bool) -> Chart<'a> {
+        self.x_labels_autohide = autohide;
+        self
+    }
+
     /// Sets the constraints used to determine whether the legend should be shown or not.
     ///
     /// The tuple's first constraint is used for the width and the second for the height. If the
@@ -689,6 +1126,22 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Wraps the legend's dataset entries into `columns` columns instead of a single column,
+    /// for charts with a dozen-plus datasets that would otherwise overflow a single-column
+    /// legend's height (or get suppressed by [`Chart::hidden_legend_constraints`]).
+    ///
+    /// `Some(n)` forces exactly `n` columns (clamped to at least `1` and at most the number of
+    /// named datasets). `None` (the default) auto-fits as many columns as fit the legend's width
+    /// budget, up to one column per entry, so a legend whose entries would fit in a single column
+    /// is still spread over several columns when the width budget allows it.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn legend_columns(mut self, columns: Option<u16>) -> Chart<'a> {
+        self.legend_columns = columns;
+        self
+    }
+
     /// Compute the internal layout of the chart given the area. If the area is too small some
     /// elements may be automatically hidden
     fn layout(&self, area: Rect) -> ChartLayout {
@@ -699,7 +1152,7 @@ impl<'a> Chart<'a> {
         let mut x = area.left();
         let mut y = area.bottom() - 1;
 
-        if self.x_axis.labels.is_some() && y > area.top() {
+        if self.x_axis.labels.is_some() && y > area.top() && self.x_labels_fit(area.width) {
             layout.label_x = Some(y);
             y -= 1;
         }
@@ -740,10 +1193,12 @@ impl<'a> Chart<'a> {
                 .datasets
                 .iter()
                 .filter_map(|d| Some(d.name.as_ref()?.width() as u16));
+            let entry_count = legends.clone().count() as u16;
 
-            if let Some(inner_width) = legends.clone().max() {
-                let legend_width = inner_width + 2;
-                let legend_height = legends.count() as u16 + 2;
+            if let Some(entry_width) = legends.max() {
+                // Every column is as wide as the longest entry plus its marker swatch, so a
+                // short entry never collides with the next column over regardless of alignment.
+                let column_width = entry_width + Self::LEGEND_SWATCH_WIDTH;
 
                 let [max_legend_width] = layout.graph_area.split(
                     &Layout::horizontal([self.hidden_legend_constraints.0]).flex(Flex::Start),
@@ -752,7 +1207,19 @@ impl<'a> Chart<'a> {
                     .graph_area
                     .split(&Layout::vertical([self.hidden_legend_constraints.1]).flex(Flex::Start));
 
-                if inner_width > 0
+                // -2 for the block border, to get the width columns actually have to fit in.
+                let columns = legend_columns(
+                    entry_count,
+                    column_width,
+                    self.legend_columns,
+                    max_legend_width.width.saturating_sub(2),
+                );
+                let rows = entry_count.saturating_add(columns - 1) / columns;
+                // +2 for the block border, plus a single-column gap between each pair of columns.
+                let legend_width = 2 + columns * column_width + (columns - 1);
+                let legend_height = rows + 2;
+
+                if entry_width > 0
                     && legend_width <= max_legend_width.width
                     && legend_height <= max_legend_height.height
                 {
@@ -771,12 +1238,40 @@ impl<'a> Chart<'a> {
                             .map(|t| t.width() as u16)
                             .unwrap_or_default(),
                     );
+                    layout.legend_columns = columns;
+                    layout.legend_column_width = column_width;
                 }
             }
         }
         layout
     }
 
+    /// The number of x-axis labels that fit in `width` without overlapping, given the widest
+    /// label and a minimum one-column gap between consecutive labels.
+    fn x_label_capacity(&self, width: u16) -> u16 {
+        let Some(labels) = self.x_axis.labels.as_ref() else {
+            return 0;
+        };
+        let max_label_width = labels.iter().map(Span::width).max().unwrap_or_default() as u16;
+        width / (max_label_width + 1)
+    }
+
+    /// Whether thinning (decimating middle labels down to a stride that fits, or hiding the row
+    /// entirely if even that doesn't fit) is active for the x-axis, via either
+    /// [`Chart::x_labels_autohide`] (on by default) or [`Axis::labels_thinning`] (opt-in, for a
+    /// single axis regardless of the chart-wide setting).
+    fn x_labels_thinning_enabled(&self) -> bool {
+        self.x_labels_autohide || self.x_axis.labels_thinning
+    }
+
+    /// Whether the x-axis labels should be rendered at all for the given `width`.
+    ///
+    /// When thinning is disabled, labels are always rendered (matching the historical behavior of
+    /// possibly overlapping). When enabled, the row is only reserved if at least two labels fit.
+    fn x_labels_fit(&self, width: u16) -> bool {
+        !self.x_labels_thinning_enabled() || self.x_label_capacity(width) >= 2
+    }
+
     fn max_width_of_labels_left_of_y_axis(&self, area: Rect, has_y_axis: bool) -> u16 {
         let mut max_width = self
             .y_axis
@@ -818,10 +1313,19 @@ impl<'a> Chart<'a> {
         let Some(y) = layout.label_x else { return };
         let labels = self.x_axis.labels.as_ref().unwrap();
         let labels_len = labels.len() as u16;
-        if labels_len < 2 {
+        if labels_len < 2 || !self.x_labels_fit(graph_area.width) {
             return;
         }
 
+        // Keep every `step`-th label (always including the first and last, which are rendered
+        // separately below) so the survivors stay evenly spaced and collision-free.
+        let step = if self.x_labels_thinning_enabled() {
+            let capacity = self.x_label_capacity(graph_area.width).max(2);
+            (labels_len + capacity - 1) / capacity
+        } else {
+            1
+        };
+
         let width_between_ticks = graph_area.width / labels_len;
 
         let label_area = self.first_x_label_area(
@@ -841,9 +1345,13 @@ impl<'a> Chart<'a> {
         Self::render_label(buf, labels.first().unwrap(), label_area, label_alignment);
 
         for (i, label) in labels[1..labels.len() - 1].iter().enumerate() {
+            let index = (i + 1) as u16;
+            if index % step != 0 {
+                continue;
+            }
             // We add 1 to x (and width-1 below) to leave at least one space before each
             // intermediate labels
-            let x = graph_area.left() + (i + 1) as u16 * width_between_ticks + 1;
+            let x = graph_area.left() + index * width_between_ticks + 1;
             let label_area = Rect::new(x, y, width_between_ticks.saturating_sub(1), 1);
 
             Self::render_label(buf, label, label_area, Alignment::Center);
@@ -901,6 +1409,9 @@ impl<'a> Chart<'a> {
         let Some(x) = layout.label_y else { return };
         let labels = self.y_axis.labels.as_ref().unwrap();
         let labels_len = labels.len() as u16;
+        if labels_len < 2 {
+            return;
+        }
         for (i, label) in labels.iter().enumerate() {
             let dy = i as u16 * (graph_area.height - 1) / (labels_len - 1);
             if dy < graph_area.bottom() {
@@ -921,6 +1432,23 @@ impl<'a> Widget for Chart<'a> {
         if area.area() == 0 {
             return;
         }
+        // Fill in bounds for axes that opted into `Axis::auto_bounds`, before anything below
+        // (including `resolved_labels`, for `Axis::auto_labels`) reads `bounds`.
+        if self.x_axis.auto_bounds {
+            let xs = self.datasets.iter().flat_map(|d| d.data.iter().map(|p| p.0));
+            self.x_axis.bounds = pad_bounds(finite_bounds(xs), self.x_axis.bounds_padding);
+        }
+        if self.y_axis.auto_bounds {
+            let ys = self.datasets.iter().flat_map(|d| d.data.iter().map(|p| p.1));
+            self.y_axis.bounds = pad_bounds(finite_bounds(ys), self.y_axis.bounds_padding);
+        }
+
+        // Fill in labels for axes that opted into `Axis::auto_labels` but weren't given an
+        // explicit `Axis::labels` vector, so the rest of layout/rendering can keep treating
+        // `labels` as the single source of truth.
+        self.x_axis.labels = self.x_axis.resolved_labels();
+        self.y_axis.labels = self.y_axis.resolved_labels();
+
         buf.set_style(area, self.style);
         // Sample the style of the entire widget. This sample will be used to reset the style of
         // the cells that are part of the components put on top of the grah area (i.e legend and
@@ -969,26 +1497,210 @@ impl<'a> Widget for Chart<'a> {
             }
         }
 
+        let x_bounds = scaled_bounds(self.x_axis.bounds, self.x_axis.scale);
+        let y_bounds = scaled_bounds(self.y_axis.bounds, self.y_axis.scale);
+
         for dataset in &self.datasets {
+            let scale_point = |(x, y): (f64, f64)| {
+                (
+                    scaled_value(x, self.x_axis.scale),
+                    scaled_value(y, self.y_axis.scale),
+                )
+            };
+
+            // A dataset the caller has asserted is ascending in x is collected once and culled to
+            // the visible range with a binary search instead of a bounds test per point: `window`
+            // is the points within
+            // `x_axis.bounds`, widened by one index on each side so segments entering/leaving the
+            // viewport are still drawn. Datasets that aren't marked as sorted fall back to
+            // `GraphData::in_bounds`, which is a full scan by default but lets custom sources
+            // (e.g. a ring buffer) provide their own fast path.
+            let window: Option<Vec<(f64, f64)>> = dataset.sorted_by_x.then(|| {
+                let full: Vec<(f64, f64)> = dataset.data.iter().collect();
+                let (start, end) = sorted_visible_range(&full, self.x_axis.bounds);
+                full[start..end].to_vec()
+            });
+
+            // Scatter and Bar plot each point independently, so points outside the visible
+            // x-range can be skipped entirely.
+            let visible: Vec<(f64, f64)> = match &window {
+                Some(window) => window
+                    .iter()
+                    .copied()
+                    .filter(|&(x, _)| x >= self.x_axis.bounds[0] && x <= self.x_axis.bounds[1])
+                    .map(scale_point)
+                    .collect(),
+                None => dataset
+                    .data
+                    .in_bounds(self.x_axis.bounds)
+                    .map(scale_point)
+                    .collect(),
+            };
+
             Canvas::default()
                 .background_color(self.style.bg.unwrap_or(Color::Reset))
-                .x_bounds(self.x_axis.bounds)
-                .y_bounds(self.y_axis.bounds)
+                .x_bounds(x_bounds)
+                .y_bounds(y_bounds)
                 .marker(dataset.marker)
                 .paint(|ctx| {
                     ctx.draw(&Points {
-                        coords: dataset.data,
+                        coords: &visible,
                         color: dataset.style.fg.unwrap_or(Color::Reset),
                     });
-                    if let GraphType::Line = dataset.graph_type {
-                        for data in dataset.data.windows(2) {
-                            ctx.draw(&CanvasLine {
-                                x1: data[0].0,
-                                y1: data[0].1,
-                                x2: data[1].0,
-                                y2: data[1].1,
-                                color: dataset.style.fg.unwrap_or(Color::Reset),
-                            });
+                    match dataset.graph_type {
+                        GraphType::Scatter => {}
+                        GraphType::Line => {
+                            // Segments that cross into view from an out-of-range neighbor still
+                            // need that neighbor, so `window` (already widened by one index on
+                            // each side) is walked whole rather than `visible`.
+                            let data: Vec<(f64, f64)> = match &window {
+                                Some(window) => {
+                                    window.iter().copied().map(scale_point).collect()
+                                }
+                                None => dataset.data.iter().map(scale_point).collect(),
+                            };
+                            for points in data.windows(2) {
+                                if let Some((p1, p2)) =
+                                    clip_line(points[0], points[1], x_bounds, y_bounds)
+                                {
+                                    ctx.draw(&CanvasLine {
+                                        x1: p1.0,
+                                        y1: p1.1,
+                                        x2: p2.0,
+                                        y2: p2.1,
+                                        color: dataset.style.fg.unwrap_or(Color::Reset),
+                                    });
+                                }
+                            }
+                        }
+                        GraphType::Step => {
+                            // Segments that cross into view from an out-of-range neighbor still
+                            // need that neighbor, so `window` (already widened by one index on
+                            // each side) is walked whole rather than `visible`.
+                            let data: Vec<(f64, f64)> = match &window {
+                                Some(window) => {
+                                    window.iter().copied().map(scale_point).collect()
+                                }
+                                None => dataset.data.iter().map(scale_point).collect(),
+                            };
+                            let color = dataset.style.fg.unwrap_or(Color::Reset);
+                            for points in data.windows(2) {
+                                let ((x1, y1), (x2, _)) = (points[0], points[1]);
+                                // Horizontal run at the earlier point's y, then a vertical jump
+                                // up to the next point's y, forming the staircase shape.
+                                if let Some((p1, p2)) =
+                                    clip_line((x1, y1), (x2, y1), x_bounds, y_bounds)
+                                {
+                                    ctx.draw(&CanvasLine {
+                                        x1: p1.0,
+                                        y1: p1.1,
+                                        x2: p2.0,
+                                        y2: p2.1,
+                                        color,
+                                    });
+                                }
+                                if let Some((p1, p2)) =
+                                    clip_line((x2, y1), points[1], x_bounds, y_bounds)
+                                {
+                                    ctx.draw(&CanvasLine {
+                                        x1: p1.0,
+                                        y1: p1.1,
+                                        x2: p2.0,
+                                        y2: p2.1,
+                                        color,
+                                    });
+                                }
+                            }
+                        }
+                        GraphType::Bar => {
+                            let baseline = 0.0_f64.clamp(y_bounds[0], y_bounds[1]);
+                            for &(x, y) in &visible {
+                                if let Some((p1, p2)) =
+                                    clip_line((x, baseline), (x, y), x_bounds, y_bounds)
+                                {
+                                    ctx.draw(&CanvasLine {
+                                        x1: p1.0,
+                                        y1: p1.1,
+                                        x2: p2.0,
+                                        y2: p2.1,
+                                        color: dataset.style.fg.unwrap_or(Color::Reset),
+                                    });
+                                }
+                            }
+                        }
+                        GraphType::Area => {
+                            // Segments that cross into view from an out-of-range neighbor still
+                            // need that neighbor, so `window` (already widened by one index on
+                            // each side) is walked whole rather than `visible`.
+                            let data: Vec<(f64, f64)> = match &window {
+                                Some(window) => {
+                                    window.iter().copied().map(scale_point).collect()
+                                }
+                                None => dataset.data.iter().map(scale_point).collect(),
+                            };
+                            let baseline = 0.0_f64.clamp(y_bounds[0], y_bounds[1]);
+                            let color = dataset.style.fg.unwrap_or(Color::Reset);
+                            // One fill column per terminal column covered by the graph area; this
+                            // is coarser than the canvas's own marker resolution, but it's enough
+                            // to make the fill look solid without a pass per dot.
+                            let step =
+                                (x_bounds[1] - x_bounds[0]) / f64::from(graph_area.width.max(1));
+                            for points in data.windows(2) {
+                                if let Some((p1, p2)) =
+                                    clip_line(points[0], points[1], x_bounds, y_bounds)
+                                {
+                                    ctx.draw(&CanvasLine {
+                                        x1: p1.0,
+                                        y1: p1.1,
+                                        x2: p2.0,
+                                        y2: p2.1,
+                                        color,
+                                    });
+                                }
+                                let ((x1, y1), (x2, y2)) = (points[0], points[1]);
+                                if step <= 0.0 || x1 == x2 {
+                                    continue;
+                                }
+                                let (left, right, left_y, right_y) = if x1 <= x2 {
+                                    (x1, x2, y1, y2)
+                                } else {
+                                    (x2, x1, y2, y1)
+                                };
+                                // Bound the columns we actually iterate to the visible x range.
+                                // `step` is sized off the visible axis, so deriving first/last
+                                // column from the raw (possibly far-out-of-view) segment bounds
+                                // can make this loop run for millions of iterations on an
+                                // outlier point; `left`/`right` themselves stay unclamped below
+                                // so the slope used for `t` still reflects the real segment.
+                                let visible_left = left.max(x_bounds[0]);
+                                let visible_right = right.min(x_bounds[1]);
+                                if visible_left > visible_right {
+                                    continue;
+                                }
+                                let first_column =
+                                    ((visible_left - x_bounds[0]) / step).ceil() as i64;
+                                let last_column =
+                                    ((visible_right - x_bounds[0]) / step).floor() as i64;
+                                for column in first_column..=last_column {
+                                    let x = x_bounds[0] + column as f64 * step;
+                                    if x < left || x > right {
+                                        continue;
+                                    }
+                                    let t = (x - left) / (right - left);
+                                    let y = left_y + t * (right_y - left_y);
+                                    if let Some((p1, p2)) =
+                                        clip_line((x, baseline), (x, y), x_bounds, y_bounds)
+                                    {
+                                        ctx.draw(&CanvasLine {
+                                            x1: p1.0,
+                                            y1: p1.1,
+                                            x2: p2.0,
+                                            y2: p2.1,
+                                            color,
+                                        });
+                                    }
+                                }
+                            }
                         }
                     }
                 })
@@ -1037,18 +1749,41 @@ impl<'a> Widget for Chart<'a> {
                 .borders(Borders::ALL)
                 .render(legend_area, buf);
 
-            for (i, (dataset_name, dataset_style)) in self
+            let columns = layout.legend_columns.max(1);
+            let column_width = layout.legend_column_width;
+
+            for (i, dataset) in self
                 .datasets
                 .iter()
-                .filter_map(|ds| Some((ds.name.as_ref()?, ds.style())))
+                .filter(|ds| ds.name.is_some())
                 .enumerate()
             {
-                let name = dataset_name.clone().patch_style(dataset_style);
+                let dataset_name = dataset.name.as_ref().unwrap();
+                let i = i as u16;
+                let column_x = legend_area.x + 1 + (i % columns) * (column_width + 1);
+                let y = legend_area.y + 1 + i / columns;
+
+                // Only draw the swatch if there's room for it and at least the name; otherwise
+                // fall back to the name alone, matching the pre-swatch layout.
+                let needs_swatch = dataset_name.width() as u16 + Self::LEGEND_SWATCH_WIDTH;
+                let name_x = if column_width >= needs_swatch {
+                    buf.get_mut(column_x, y)
+                        .set_char(legend_swatch(dataset.marker))
+                        .set_style(Style::default().fg(dataset.style.fg.unwrap_or(Color::Reset)));
+                    column_x + Self::LEGEND_SWATCH_WIDTH
+                } else {
+                    column_x
+                };
+
+                // Bounded to this column's share of `column_width` (rather than out to
+                // `legend_area`'s right edge) so a right-aligned name can't bleed into the next
+                // column.
+                let name = dataset_name.clone().patch_style(dataset.style());
                 name.render(
                     Rect {
-                        x: legend_area.x + 1,
-                        y: legend_area.y + 1 + i as u16,
-                        width: legend_area.width - 2,
+                        x: name_x,
+                        y,
+                        width: column_width - (name_x - column_x),
                         height: 1,
                     },
                     buf,
@@ -1058,6 +1793,125 @@ impl<'a> Widget for Chart<'a> {
     }
 }
 
+/// Returns the character drawn in a legend's swatch column for a dataset using `marker`, so the
+/// legend gives a visual hint of what the dataset looks like on the graph rather than just its
+/// name.
+fn legend_swatch(marker: symbols::Marker) -> char {
+    match marker {
+        symbols::Marker::Dot => symbols::DOT.chars().next().unwrap_or('•'),
+        symbols::Marker::Block => symbols::block::FULL.chars().next().unwrap_or('█'),
+        symbols::Marker::Bar => symbols::bar::HALF.chars().next().unwrap_or('▄'),
+        symbols::Marker::Braille => '⣿',
+        symbols::Marker::HalfBlock => symbols::half_block::FULL.chars().next().unwrap_or('█'),
+    }
+}
+
+/// Picks how many columns to wrap a legend's `entry_count` dataset entries into.
+///
+/// `forced` overrides auto-fitting with an exact column count (clamped to `[1, entry_count]`).
+/// Otherwise, greedily packs as many `column_width`-wide columns, each separated by a single
+/// column of padding, as fit in `available_width` -- the legend's interior width budget, with its
+/// border already subtracted by the caller.
+fn legend_columns(
+    entry_count: u16,
+    column_width: u16,
+    forced: Option<u16>,
+    available_width: u16,
+) -> u16 {
+    let columns = match forced {
+        Some(columns) => columns,
+        None if available_width < column_width => 1,
+        None => 1 + (available_width - column_width) / (column_width + 1),
+    };
+    columns.clamp(1, entry_count.max(1))
+}
+
+/// Returns the `[start, end)` index range of `data` (assumed ascending in x) covering
+/// `x_bounds`, widened by one index on each side so segments entering/leaving the viewport
+/// still have the off-screen neighbor they need to be drawn.
+fn sorted_visible_range(data: &[(f64, f64)], x_bounds: [f64; 2]) -> (usize, usize) {
+    let start = data
+        .partition_point(|&(x, _)| x < x_bounds[0])
+        .saturating_sub(1);
+    let end = (data.partition_point(|&(x, _)| x <= x_bounds[1]) + 1).min(data.len());
+    (start, end)
+}
+
+/// Outcode bits used by [`clip_line`]'s Cohen–Sutherland region test.
+const CLIP_LEFT: u8 = 0b0001;
+const CLIP_RIGHT: u8 = 0b0010;
+const CLIP_BOTTOM: u8 = 0b0100;
+const CLIP_TOP: u8 = 0b1000;
+
+/// Computes the Cohen–Sutherland outcode of `point` relative to `x_bounds`/`y_bounds`.
+fn outcode((x, y): (f64, f64), x_bounds: [f64; 2], y_bounds: [f64; 2]) -> u8 {
+    let mut code = 0;
+    if x < x_bounds[0] {
+        code |= CLIP_LEFT;
+    } else if x > x_bounds[1] {
+        code |= CLIP_RIGHT;
+    }
+    if y < y_bounds[0] {
+        code |= CLIP_BOTTOM;
+    } else if y > y_bounds[1] {
+        code |= CLIP_TOP;
+    }
+    code
+}
+
+/// Clips the segment from `p1` to `p2` to the `x_bounds`/`y_bounds` rectangle using the
+/// Cohen–Sutherland algorithm, returning the visible portion of the segment, if any.
+///
+/// Each endpoint is assigned a 4-bit outcode describing which side(s) of the bounds it falls
+/// outside of. A segment is trivially accepted once both outcodes are zero, and trivially
+/// rejected once their bitwise AND is nonzero (both endpoints lie outside the same edge).
+/// Otherwise, the out-of-bounds endpoint is replaced by its intersection with the offending edge,
+/// computed by linearly interpolating between the two original points, and the process repeats.
+///
+/// This gives `GraphType::Line`/`Area`/`Bar` the same outcome a parametric (Liang–Barsky style)
+/// clip would: a segment with one endpoint outside the viewport is drawn up to the graph edge
+/// instead of vanishing, without needing a second clipping implementation alongside this one.
+fn clip_line(
+    mut p1: (f64, f64),
+    mut p2: (f64, f64),
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+) -> Option<((f64, f64), (f64, f64))> {
+    let mut code1 = outcode(p1, x_bounds, y_bounds);
+    let mut code2 = outcode(p2, x_bounds, y_bounds);
+    loop {
+        if code1 == 0 && code2 == 0 {
+            return Some((p1, p2));
+        }
+        if code1 & code2 != 0 {
+            return None;
+        }
+        let out_code = if code1 != 0 { code1 } else { code2 };
+        let (x1, y1) = p1;
+        let (x2, y2) = p2;
+        let point = if out_code & CLIP_TOP != 0 {
+            let y = y_bounds[1];
+            (x1 + (x2 - x1) * (y - y1) / (y2 - y1), y)
+        } else if out_code & CLIP_BOTTOM != 0 {
+            let y = y_bounds[0];
+            (x1 + (x2 - x1) * (y - y1) / (y2 - y1), y)
+        } else if out_code & CLIP_RIGHT != 0 {
+            let x = x_bounds[1];
+            (x, y1 + (y2 - y1) * (x - x1) / (x2 - x1))
+        } else {
+            let x = x_bounds[0];
+            (x, y1 + (y2 - y1) * (x - x1) / (x2 - x1))
+        };
+        if out_code == code1 {
+            p1 = point;
+            code1 = outcode(p1, x_bounds, y_bounds);
+        } else {
+            p2 = point;
+            code2 = outcode(p2, x_bounds, y_bounds);
+        }
+    }
+}
+
 impl<'a> Styled for Axis<'a> {
     type Item = Axis<'a>;
 
@@ -1153,6 +2007,66 @@ mod tests {
         )
     }
 
+    #[test]
+    fn auto_labels_generates_evenly_spaced_values() {
+        let axis = Axis::default().bounds([0.0, 100.0]).auto_labels(5);
+        assert_eq!(
+            axis.resolved_labels(),
+            Some(vec![
+                Span::from("0"),
+                Span::from("25"),
+                Span::from("50"),
+                Span::from("75"),
+                Span::from("100"),
+            ])
+        );
+    }
+
+    #[test]
+    fn auto_labels_respects_precision() {
+        let axis = Axis::default()
+            .bounds([0.0, 1.0])
+            .auto_labels(3)
+            .label_precision(2);
+        assert_eq!(
+            axis.resolved_labels(),
+            Some(vec![
+                Span::from("0.00"),
+                Span::from("0.50"),
+                Span::from("1.00"),
+            ])
+        );
+    }
+
+    #[test]
+    fn auto_labels_ignored_when_explicit_labels_set() {
+        let axis = Axis::default()
+            .bounds([0.0, 100.0])
+            .auto_labels(5)
+            .labels(vec!["custom".into()]);
+        assert_eq!(axis.resolved_labels(), Some(vec![Span::from("custom")]));
+    }
+
+    #[test]
+    fn no_auto_labels_and_no_explicit_labels_resolves_to_none() {
+        assert_eq!(Axis::default().resolved_labels(), None);
+    }
+
+    #[test]
+    fn time_window_sets_bounds_to_now_minus_window() {
+        let axis = Axis::default().time_window(100.0, 10.0);
+        assert_eq!(axis.bounds, [90.0, 100.0]);
+    }
+
+    #[test]
+    fn time_window_formats_auto_labels_as_relative_durations() {
+        let axis = Axis::default().time_window(100.0, 10.0).auto_labels(3);
+        assert_eq!(
+            axis.resolved_labels(),
+            Some(vec![Span::from("-10s"), Span::from("-5s"), Span::from("0s")])
+        );
+    }
+
     #[test]
     fn dataset_can_be_stylized() {
         assert_eq!(
@@ -1181,15 +2095,300 @@ mod tests {
     fn graph_type_to_string() {
         assert_eq!(GraphType::Scatter.to_string(), "Scatter");
         assert_eq!(GraphType::Line.to_string(), "Line");
+        assert_eq!(GraphType::Bar.to_string(), "Bar");
+        assert_eq!(GraphType::Area.to_string(), "Area");
+        assert_eq!(GraphType::Step.to_string(), "Step");
     }
 
     #[test]
     fn graph_type_from_str() {
         assert_eq!("Scatter".parse::<GraphType>(), Ok(GraphType::Scatter));
         assert_eq!("Line".parse::<GraphType>(), Ok(GraphType::Line));
+        assert_eq!("Bar".parse::<GraphType>(), Ok(GraphType::Bar));
+        assert_eq!("Area".parse::<GraphType>(), Ok(GraphType::Area));
+        assert_eq!("Step".parse::<GraphType>(), Ok(GraphType::Step));
         assert_eq!("".parse::<GraphType>(), Err(ParseError::VariantNotFound));
     }
 
+    #[test]
+    fn axis_scale_to_string() {
+        assert_eq!(AxisScale::Linear.to_string(), "Linear");
+        assert_eq!(AxisScale::Log.to_string(), "Log");
+    }
+
+    #[test]
+    fn axis_scale_from_str() {
+        assert_eq!("Linear".parse::<AxisScale>(), Ok(AxisScale::Linear));
+        assert_eq!("Log".parse::<AxisScale>(), Ok(AxisScale::Log));
+        assert_eq!("".parse::<AxisScale>(), Err(ParseError::VariantNotFound));
+    }
+
+    #[test]
+    fn axis_scale_default_is_linear() {
+        assert_eq!(AxisScale::default(), AxisScale::Linear);
+    }
+
+    #[test]
+    fn scaled_value_linear_is_unchanged() {
+        assert_eq!(scaled_value(42.0, AxisScale::Linear), 42.0);
+    }
+
+    #[test]
+    fn scaled_value_log_uses_log10() {
+        assert_eq!(scaled_value(100.0, AxisScale::Log), 2.0);
+    }
+
+    #[test]
+    fn scaled_value_log_clamps_nonpositive_values() {
+        assert_eq!(
+            scaled_value(0.0, AxisScale::Log),
+            f64::MIN_POSITIVE.log10()
+        );
+        assert_eq!(
+            scaled_value(-5.0, AxisScale::Log),
+            f64::MIN_POSITIVE.log10()
+        );
+        assert!(scaled_value(0.0, AxisScale::Log).is_finite());
+    }
+
+    #[test]
+    fn scaled_bounds_log() {
+        assert_eq!(scaled_bounds([1.0, 1000.0], AxisScale::Log), [0.0, 3.0]);
+    }
+
+    #[test]
+    fn finite_bounds_ignores_nan_and_infinite_values() {
+        let values = [1.0, f64::NAN, -5.0, f64::INFINITY, f64::NEG_INFINITY, 3.0];
+        assert_eq!(finite_bounds(values.into_iter()), [-5.0, 3.0]);
+    }
+
+    #[test]
+    fn finite_bounds_falls_back_to_zero_when_nothing_is_finite() {
+        let values = [f64::NAN, f64::INFINITY, f64::NEG_INFINITY];
+        assert_eq!(finite_bounds(values.into_iter()), [0.0, 0.0]);
+    }
+
+    #[test]
+    fn pad_bounds_expands_by_fraction_of_span() {
+        assert_eq!(pad_bounds([0.0, 10.0], 0.1), [-1.0, 11.0]);
+    }
+
+    #[test]
+    fn pad_bounds_is_a_no_op_for_nonpositive_fraction() {
+        assert_eq!(pad_bounds([0.0, 10.0], 0.0), [0.0, 10.0]);
+        assert_eq!(pad_bounds([0.0, 10.0], -0.5), [0.0, 10.0]);
+    }
+
+    #[test]
+    fn graph_data_slice() {
+        let data: &[(f64, f64)] = &[(0.0, 1.0), (1.0, 2.0), (2.0, 3.0)];
+        let data: &dyn GraphData = data;
+        assert_eq!(data.len(), 3);
+        assert!(!data.is_empty());
+        assert_eq!(
+            data.iter().collect::<Vec<_>>(),
+            vec![(0.0, 1.0), (1.0, 2.0), (2.0, 3.0)]
+        );
+    }
+
+    #[test]
+    fn graph_data_array() {
+        let data = [(0.0, 1.0), (5.0, 6.0)];
+        let data: &dyn GraphData = &data;
+        assert_eq!(data.len(), 2);
+    }
+
+    #[test]
+    fn graph_data_vec() {
+        let data: Vec<(f64, f64)> = vec![(0.0, 1.0), (5.0, 6.0), (10.0, 11.0)];
+        let data: &dyn GraphData = &data;
+        assert_eq!(data.len(), 3);
+    }
+
+    #[test]
+    fn graph_data_is_empty() {
+        let data: &[(f64, f64)] = &[];
+        let data: &dyn GraphData = data;
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn graph_data_in_bounds_filters_by_x() {
+        let data: &[(f64, f64)] = &[(0.0, 1.0), (5.0, 2.0), (10.0, 3.0)];
+        let data: &dyn GraphData = data;
+        assert_eq!(
+            data.in_bounds([4.0, 11.0]).collect::<Vec<_>>(),
+            vec![(5.0, 2.0), (10.0, 3.0)]
+        );
+    }
+
+    #[test]
+    fn time_series_drops_oldest_point_past_capacity() {
+        let mut series = TimeSeries::new(2);
+        series.push(0.0, 1.0);
+        series.push(1.0, 2.0);
+        series.push(2.0, 3.0);
+        assert_eq!(series.iter().collect::<Vec<_>>(), vec![(1.0, 2.0), (2.0, 3.0)]);
+    }
+
+    #[test]
+    fn time_series_retain_window_drops_points_older_than_the_window() {
+        let mut series = TimeSeries::new(10);
+        for t in 0..10 {
+            series.push(f64::from(t), f64::from(t));
+        }
+        series.retain_window(9.0, 3.0);
+        assert_eq!(
+            series.iter().collect::<Vec<_>>(),
+            vec![(6.0, 6.0), (7.0, 7.0), (8.0, 8.0), (9.0, 9.0)]
+        );
+    }
+
+    #[test]
+    fn dataset_equality_compares_data_contents() {
+        let a = Dataset::default().data(&[(0.0, 1.0), (2.0, 3.0)]);
+        let b = Dataset::default().data(&[(0.0, 1.0), (2.0, 3.0)]);
+        let c = Dataset::default().data(&[(0.0, 1.0)]);
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn x_label_capacity() {
+        let chart = Chart::new(vec![]).x_axis(
+            Axis::default().labels(vec!["0".into(), "50".into(), "100".into()]),
+        );
+        // widest label is "100" (3 cols), so 1 fits every 4 columns
+        assert_eq!(chart.x_label_capacity(12), 3);
+        assert_eq!(chart.x_label_capacity(7), 1);
+    }
+
+    #[test]
+    fn x_labels_fit_ignores_width_when_thinning_disabled() {
+        let chart = Chart::new(vec![])
+            .x_labels_autohide(false)
+            .x_axis(Axis::default().labels(vec!["0".into(), "100".into()]));
+        assert!(chart.x_labels_fit(1));
+    }
+
+    #[test]
+    fn x_labels_autohide_is_on_by_default() {
+        let chart = Chart::new(vec![])
+            .x_axis(Axis::default().labels(vec!["0".into(), "100".into()]));
+        assert!(!chart.x_labels_fit(1));
+    }
+
+    #[test]
+    fn x_labels_fit_hides_when_too_narrow_for_thinning() {
+        let chart = Chart::new(vec![]).x_axis(
+            Axis::default()
+                .labels(vec!["0".into(), "100".into()])
+                .labels_thinning(true),
+        );
+        assert!(!chart.x_labels_fit(3));
+        assert!(chart.x_labels_fit(8));
+    }
+
+    #[test]
+    fn thinning_drops_labels_that_would_overlap() {
+        let labels: Vec<String> = (0..10).map(|i| (i * 10).to_string()).collect();
+        let chart = Chart::new(vec![Dataset::default().data(&[(0.0, 0.0), (90.0, 10.0)])])
+            .x_axis(
+                Axis::default()
+                    .bounds([0.0, 90.0])
+                    .labels(labels.iter().cloned().map(Into::into).collect())
+                    .labels_thinning(true),
+            );
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 5));
+        chart.render(buffer.area, &mut buffer);
+
+        // every rendered token must be one of the original labels intact (no merged/corrupted
+        // overlap), and there can be no more tokens than fit the width without collisions
+        let label_row: String = (0..buffer.area.width)
+            .map(|x| buffer.get(x, buffer.area.height - 1).symbol())
+            .collect();
+        let tokens: Vec<&str> = label_row.split_whitespace().collect();
+        for token in &tokens {
+            assert!(labels.iter().any(|l| l == token), "corrupted label: {token}");
+        }
+        assert!(tokens.len() <= chart.x_label_capacity(20) as usize);
+    }
+
+    #[test]
+    fn thinning_hides_labels_entirely_when_area_is_too_narrow() {
+        let labels: Vec<Span> = (0..10).map(|i| (i * 10).to_string().into()).collect();
+        let area = Rect::new(0, 0, 3, 5);
+
+        let without_thinning = Chart::new(vec![])
+            .x_labels_autohide(false)
+            .x_axis(Axis::default().labels(labels.clone()));
+        let with_thinning = Chart::new(vec![])
+            .x_axis(Axis::default().labels(labels).labels_thinning(true));
+
+        let thinned_layout = with_thinning.layout(area);
+        assert_eq!(thinned_layout.label_x, None);
+        assert_eq!(
+            thinned_layout.graph_area.height,
+            without_thinning.layout(area).graph_area.height + 1
+        );
+    }
+
+    #[test]
+    fn legend_swatch_matches_marker() {
+        assert_eq!(legend_swatch(symbols::Marker::Dot), '•');
+        assert_eq!(legend_swatch(symbols::Marker::Block), '█');
+        assert_eq!(legend_swatch(symbols::Marker::Bar), '▄');
+        assert_eq!(legend_swatch(symbols::Marker::Braille), '⣿');
+        assert_eq!(legend_swatch(symbols::Marker::HalfBlock), '█');
+    }
+
+    #[test]
+    fn legend_columns_auto_fits_as_many_as_the_width_allows() {
+        assert_eq!(legend_columns(6, 5, None, 17), 3);
+        assert_eq!(legend_columns(6, 5, None, 4), 1);
+    }
+
+    #[test]
+    fn legend_columns_forced_count_is_clamped_to_entry_count() {
+        assert_eq!(legend_columns(3, 5, Some(10), 100), 3);
+        assert_eq!(legend_columns(3, 5, Some(0), 100), 1);
+    }
+
+    #[test]
+    fn legend_columns_wraps_entries_into_a_grid() {
+        let datasets = ["A", "B", "C", "D"].map(|name| Dataset::default().name(name));
+        let chart = Chart::new(datasets.into())
+            .hidden_legend_constraints((Constraint::Percentage(100), Constraint::Percentage(100)))
+            .legend_position(Some(LegendPosition::TopLeft))
+            .legend_columns(Some(2));
+        let area = Rect::new(0, 0, 9, 4);
+        let mut buffer = Buffer::empty(area);
+
+        chart.render(area, &mut buffer);
+
+        let expected = Buffer::with_lines(vec![
+            "┌───────┐",
+            "│• A • B│",
+            "│• C • D│",
+            "└───────┘",
+        ]);
+        assert_buffer_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn legend_swatch_is_colored_like_its_dataset() {
+        let chart = Chart::new(vec![Dataset::default().name("Ds1").style(Color::Red)])
+            .hidden_legend_constraints((Constraint::Percentage(100), Constraint::Percentage(100)))
+            .legend_position(Some(LegendPosition::TopLeft));
+        let area = Rect::new(0, 0, 10, 3);
+        let mut buffer = Buffer::empty(area);
+
+        chart.render(area, &mut buffer);
+
+        assert_eq!(buffer.get(1, 1).symbol(), "•");
+        assert_eq!(buffer.get(1, 1).style().fg, Some(Color::Red));
+    }
+
     #[test]
     fn it_does_not_panic_if_title_is_wider_than_buffer() {
         let widget = Chart::default()
@@ -1201,6 +2400,13 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(vec![" ".repeat(8); 4]))
     }
 
+    #[test]
+    fn it_does_not_panic_with_a_single_y_axis_label() {
+        let widget = Chart::default().y_axis(Axis::default().bounds([0.0, 100.0]).auto_labels(1));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 10));
+        widget.render(buffer.area, &mut buffer);
+    }
+
     #[test]
     fn datasets_without_name_dont_contribute_to_legend_height() {
         let data_named_1 = Dataset::default().name("data1"); // must occupy a row in legend
@@ -1236,10 +2442,10 @@ mod tests {
         widget.render(buffer.area, &mut buffer);
 
         let expected = Buffer::with_lines(vec![
-            "    ┌──────────────┐",
-            "    │Very long name│",
-            "    │    Short name│",
-            "    └──────────────┘",
+            "  ┌────────────────┐",
+            "  │• Very long name│",
+            "  │•     Short name│",
+            "  └────────────────┘",
             "                    ",
         ]);
         assert_buffer_eq!(buffer, expected);
@@ -1256,9 +2462,9 @@ mod tests {
         chart.render(buffer.area, &mut buffer);
 
         let expected = Buffer::with_lines(vec![
-            "┌───┐                         ",
-            "│Ds1│                         ",
-            "└───┘                         ",
+            "┌─────┐                       ",
+            "│• Ds1│                       ",
+            "└─────┘                       ",
             "                              ",
             "                              ",
             "                              ",
@@ -1293,9 +2499,9 @@ mod tests {
 
         let expected = Buffer::with_lines(vec![
             "The title overlap a legend.   ",
-            "                         ┌───┐",
-            "                         │Ds1│",
-            "                         └───┘",
+            "                       ┌─────┐",
+            "                       │• Ds1│",
+            "                       └─────┘",
             "                              ",
             "                              ",
             "                              ",
@@ -1349,10 +2555,10 @@ mod tests {
         let chart = Chart::new(vec![Dataset::default().name(name)])
             .hidden_legend_constraints((Constraint::Percentage(100), Constraint::Percentage(100)));
 
-        let area = Rect::new(0, 0, name.len() as u16 + 2, 3);
+        let area = Rect::new(0, 0, name.len() as u16 + 2 + Chart::LEGEND_SWATCH_WIDTH, 3);
         let mut buffer = Buffer::empty(area);
 
-        let expected = Buffer::with_lines(vec!["┌────┐", "│Data│", "└────┘"]);
+        let expected = Buffer::with_lines(vec!["┌──────┐", "│• Data│", "└──────┘"]);
 
         [
             LegendPosition::TopLeft,
@@ -1390,9 +2596,9 @@ mod tests {
         assert_eq!(
             buffer,
             Buffer::with_lines(vec![
-                "┌────┐   ",
-                "│Data│   ",
-                "└────┘   ",
+                "┌──────┐ ",
+                "│• Data│ ",
+                "└──────┘ ",
                 "         ",
                 "         ",
                 "         ",
@@ -1408,9 +2614,9 @@ mod tests {
         assert_eq!(
             buffer,
             Buffer::with_lines(vec![
-                " ┌────┐  ",
-                " │Data│  ",
-                " └────┘  ",
+                "┌──────┐ ",
+                "│• Data│ ",
+                "└──────┘ ",
                 "         ",
                 "         ",
                 "         ",
@@ -1425,9 +2631,9 @@ mod tests {
         assert_eq!(
             buffer,
             Buffer::with_lines(vec![
-                "   ┌────┐",
-                "   │Data│",
-                "   └────┘",
+                " ┌──────┐",
+                " │• Data│",
+                " └──────┘",
                 "         ",
                 "         ",
                 "         ",
@@ -1443,9 +2649,9 @@ mod tests {
             buffer,
             Buffer::with_lines(vec![
                 "         ",
-                "┌────┐   ",
-                "│Data│   ",
-                "└────┘   ",
+                "┌──────┐ ",
+                "│• Data│ ",
+                "└──────┘ ",
                 "         ",
                 "         ",
             ])
@@ -1461,9 +2667,9 @@ mod tests {
             buffer,
             Buffer::with_lines(vec![
                 "         ",
-                "   ┌────┐",
-                "   │Data│",
-                "   └────┘",
+                " ┌──────┐",
+                " │• Data│",
+                " └──────┘",
                 "         ",
                 "         ",
             ])
@@ -1480,9 +2686,9 @@ mod tests {
                 "         ",
                 "         ",
                 "         ",
-                "┌────┐   ",
-                "│Data│   ",
-                "└────┘   ",
+                "┌──────┐ ",
+                "│• Data│ ",
+                "└──────┘ ",
             ])
         );
 
@@ -1497,9 +2703,9 @@ mod tests {
                 "         ",
                 "         ",
                 "         ",
-                " ┌────┐  ",
-                " │Data│  ",
-                " └────┘  ",
+                "┌──────┐ ",
+                "│• Data│ ",
+                "└──────┘ ",
             ])
         );
 
@@ -1514,9 +2720,9 @@ mod tests {
                 "         ",
                 "         ",
                 "         ",
-                "   ┌────┐",
-                "   │Data│",
-                "   └────┘",
+                " ┌──────┐",
+                " │• Data│",
+                " └──────┘",
             ])
         );
 
@@ -1535,4 +2741,241 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn sorted_visible_range_widens_by_one_on_each_side() {
+        let data: Vec<(f64, f64)> = (0..10).map(|x| (f64::from(x), 0.0)).collect();
+        assert_eq!(sorted_visible_range(&data, [3.0, 6.0]), (2, 8));
+    }
+
+    #[test]
+    fn sorted_visible_range_empty_data() {
+        let data: Vec<(f64, f64)> = vec![];
+        assert_eq!(sorted_visible_range(&data, [0.0, 1.0]), (0, 0));
+    }
+
+    #[test]
+    fn sorted_visible_range_all_points_left_of_bounds() {
+        let data = [(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)];
+        assert_eq!(sorted_visible_range(&data, [10.0, 20.0]), (2, 3));
+    }
+
+    #[test]
+    fn sorted_visible_range_all_points_right_of_bounds() {
+        let data = [(10.0, 0.0), (11.0, 0.0), (12.0, 0.0)];
+        assert_eq!(sorted_visible_range(&data, [0.0, 1.0]), (0, 1));
+    }
+
+    #[test]
+    fn sorted_visible_range_single_visible_point() {
+        let data = [(0.0, 0.0), (5.0, 0.0), (10.0, 0.0)];
+        assert_eq!(sorted_visible_range(&data, [5.0, 5.0]), (0, 3));
+    }
+
+    #[test]
+    fn dataset_sorted_by_x_defaults_to_false() {
+        assert!(!Dataset::default().sorted_by_x);
+    }
+
+    #[test]
+    fn clip_line_fully_inside_is_unchanged() {
+        let clipped = clip_line((1.0, 1.0), (4.0, 4.0), [0.0, 5.0], [0.0, 5.0]);
+        assert_eq!(clipped, Some(((1.0, 1.0), (4.0, 4.0))));
+    }
+
+    #[test]
+    fn clip_line_fully_outside_is_rejected() {
+        let clipped = clip_line((10.0, 10.0), (20.0, 20.0), [0.0, 5.0], [0.0, 5.0]);
+        assert_eq!(clipped, None);
+    }
+
+    #[test]
+    fn clip_line_partially_outside_is_clipped() {
+        let clipped = clip_line((-5.0, 0.0), (5.0, 0.0), [0.0, 10.0], [-1.0, 1.0]);
+        assert_eq!(clipped, Some(((0.0, 0.0), (5.0, 0.0))));
+    }
+
+    #[test]
+    fn clip_line_crossing_a_corner_is_clipped_on_both_ends() {
+        let clipped = clip_line((-5.0, -5.0), (15.0, 15.0), [0.0, 10.0], [0.0, 10.0]);
+        assert_eq!(clipped, Some(((0.0, 0.0), (10.0, 10.0))));
+    }
+
+    #[test]
+    fn area_graph_type_fills_down_to_baseline() {
+        let area = Rect::new(0, 0, 5, 5);
+        let data = [(0.0, 2.0), (4.0, 2.0)];
+
+        let render = |graph_type| {
+            let dataset = Dataset::default()
+                .data(&data)
+                .marker(symbols::Marker::Block)
+                .graph_type(graph_type);
+            let chart = Chart::new(vec![dataset])
+                .x_axis(Axis::default().bounds([0.0, 4.0]))
+                .y_axis(Axis::default().bounds([0.0, 4.0]));
+            let mut buffer = Buffer::empty(area);
+            chart.render(area, &mut buffer);
+            buffer
+        };
+
+        let painted_bottom_row = |buffer: &Buffer| {
+            (0..area.width)
+                .filter(|&x| buffer.get(x, area.height - 1).symbol() != " ")
+                .count()
+        };
+
+        // The dataset is a flat line at y = 2, so only `GraphType::Area` should paint anything
+        // down at the y = 0 baseline row.
+        assert_eq!(painted_bottom_row(&render(GraphType::Line)), 0);
+        assert!(painted_bottom_row(&render(GraphType::Area)) > 0);
+    }
+
+    #[test]
+    fn area_graph_type_fills_a_segment_clipped_at_the_viewport_edge() {
+        // The second point is well past x_bounds[1] == 4.0, so without clipping the whole
+        // segment (and its fill) would be dropped; the visible portion should still be filled
+        // down to the baseline all the way to the right edge.
+        let area = Rect::new(0, 0, 5, 5);
+        let data = [(0.0, 2.0), (100.0, 2.0)];
+        let dataset = Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Block)
+            .graph_type(GraphType::Area);
+        let chart = Chart::new(vec![dataset])
+            .x_axis(Axis::default().bounds([0.0, 4.0]))
+            .y_axis(Axis::default().bounds([0.0, 4.0]));
+        let mut buffer = Buffer::empty(area);
+        chart.render(area, &mut buffer);
+
+        assert_ne!(buffer.get(area.width - 1, area.height - 1).symbol(), " ");
+    }
+
+    /// Regression test: the fill-column loop used to derive its column range from the raw,
+    /// unclipped segment endpoints. With one endpoint a million units past `x_axis.bounds`, that
+    /// made the loop iterate millions of times per render instead of just the columns actually
+    /// visible; this must stay bounded to the chart's width.
+    #[test]
+    fn area_graph_type_does_not_hang_on_a_far_out_of_bounds_point() {
+        let area = Rect::new(0, 0, 5, 5);
+        let data = [(0.0, 2.0), (1_000_000.0, 2.0)];
+        let dataset = Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Block)
+            .graph_type(GraphType::Area);
+        let chart = Chart::new(vec![dataset])
+            .x_axis(Axis::default().bounds([0.0, 4.0]))
+            .y_axis(Axis::default().bounds([0.0, 4.0]));
+        let mut buffer = Buffer::empty(area);
+        chart.render(area, &mut buffer);
+
+        assert_ne!(buffer.get(area.width - 1, area.height - 1).symbol(), " ");
+    }
+
+    #[test]
+    fn step_graph_type_holds_the_earlier_value_until_the_next_point() {
+        let area = Rect::new(0, 0, 5, 5);
+        let data = [(0.0, 0.0), (4.0, 4.0)];
+
+        let render = |graph_type| {
+            let dataset = Dataset::default()
+                .data(&data)
+                .marker(symbols::Marker::Block)
+                .graph_type(graph_type);
+            let chart = Chart::new(vec![dataset])
+                .x_axis(Axis::default().bounds([0.0, 4.0]))
+                .y_axis(Axis::default().bounds([0.0, 4.0]));
+            let mut buffer = Buffer::empty(area);
+            chart.render(area, &mut buffer);
+            buffer
+        };
+
+        let painted_bottom_row = |buffer: &Buffer| {
+            (0..area.width)
+                .filter(|&x| buffer.get(x, area.height - 1).symbol() != " ")
+                .count()
+        };
+
+        // `Line` only touches the y = 0 baseline row near its left endpoint, while `Step` holds
+        // that value flat in a horizontal run all the way to the next point's x, painting most
+        // of the bottom row.
+        assert!(
+            painted_bottom_row(&render(GraphType::Step)) > painted_bottom_row(&render(GraphType::Line))
+        );
+    }
+
+    #[test]
+    fn line_segment_with_one_endpoint_outside_bounds_is_drawn_to_the_edge() {
+        // The second point is well past x_bounds[1] == 4.0, so the segment would vanish entirely
+        // without clipping; with it, the visible portion should still reach the right edge.
+        let data = [(0.0, 2.0), (100.0, 2.0)];
+        let dataset = Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Block)
+            .graph_type(GraphType::Line);
+        let chart = Chart::new(vec![dataset])
+            .x_axis(Axis::default().bounds([0.0, 4.0]))
+            .y_axis(Axis::default().bounds([0.0, 4.0]));
+        let area = Rect::new(0, 0, 5, 5);
+        let mut buffer = Buffer::empty(area);
+        chart.render(area, &mut buffer);
+
+        let middle_row: String = (0..area.width)
+            .map(|x| buffer.get(x, area.height / 2).symbol().to_string())
+            .collect();
+        assert_ne!(middle_row.trim(), "");
+        assert_ne!(buffer.get(area.width - 1, area.height / 2).symbol(), " ");
+    }
+
+    #[test]
+    fn line_segment_with_both_endpoints_outside_bounds_still_crosses_the_viewport() {
+        // Simulates scrolling a fixed window over live data: neither endpoint of this segment
+        // is in view, but the segment passes straight through it, so it should still be drawn
+        // clipped to both edges rather than dropped entirely.
+        let data = [(-100.0, -100.0), (100.0, 100.0)];
+        let dataset = Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Block)
+            .graph_type(GraphType::Line);
+        let chart = Chart::new(vec![dataset])
+            .x_axis(Axis::default().bounds([0.0, 4.0]))
+            .y_axis(Axis::default().bounds([0.0, 4.0]));
+        let area = Rect::new(0, 0, 5, 5);
+        let mut buffer = Buffer::empty(area);
+        chart.render(area, &mut buffer);
+
+        assert_ne!(buffer.get(0, area.height - 1).symbol(), " ");
+        assert_ne!(buffer.get(area.width - 1, 0).symbol(), " ");
+    }
+
+    #[test]
+    fn auto_bounds_are_derived_from_dataset_points() {
+        let data = [(1.0, 10.0), (2.0, 20.0), (3.0, 15.0)];
+        let render = |use_auto_bounds: bool| {
+            let (mut x_axis, mut y_axis) = (Axis::default(), Axis::default());
+            if use_auto_bounds {
+                x_axis = x_axis.auto_bounds();
+                y_axis = y_axis.auto_bounds();
+            }
+            let dataset = Dataset::default().data(&data).marker(symbols::Marker::Block);
+            let chart = Chart::new(vec![dataset]).x_axis(x_axis).y_axis(y_axis);
+            let area = Rect::new(0, 0, 10, 10);
+            let mut buffer = Buffer::empty(area);
+            chart.render(area, &mut buffer);
+            buffer
+        };
+
+        let painted = |buffer: &Buffer| {
+            (0..10)
+                .flat_map(|y| (0..10).map(move |x| (x, y)))
+                .filter(|&(x, y)| buffer.get(x, y).symbol() != " ")
+                .count()
+        };
+
+        // Without `auto_bounds`, the axes stay at their default `[0.0, 0.0]`, so none of this
+        // data (all `x >= 1.0`) falls inside them and nothing is drawn. With `auto_bounds`, the
+        // axes expand to cover the dataset, so the points are painted.
+        assert_eq!(painted(&render(false)), 0);
+        assert!(painted(&render(true)) > 0);
+    }
 }
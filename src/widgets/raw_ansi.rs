@@ -0,0 +1,69 @@
+#![warn(missing_docs)]
+use std::borrow::Cow;
+
+use crate::{prelude::*, widgets::Widget};
+
+/// A widget that renders raw ANSI/SGR-escaped text directly into a [`Buffer`].
+///
+/// This is a thin wrapper around [`Text::from_ansi`]/[`Text::from_ansi_bytes`] for callers who
+/// have captured output from a subprocess, a PTY, or a log file and just want to draw it as-is,
+/// without building a [`Text`] themselves first.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui::prelude::*;
+/// # use ratatui::widgets::RawAnsi;
+/// let widget = RawAnsi::new("\x1b[31merror:\x1b[0m something went wrong");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawAnsi<'a> {
+    content: Cow<'a, str>,
+}
+
+impl<'a> RawAnsi<'a> {
+    /// Creates a new `RawAnsi` widget from a string containing ANSI/SGR escape sequences.
+    pub fn new<T>(content: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Self {
+            content: content.into(),
+        }
+    }
+
+    /// Creates a new `RawAnsi` widget from raw bytes, decoding invalid UTF-8 lossily.
+    pub fn from_bytes(content: &[u8]) -> RawAnsi<'static> {
+        RawAnsi {
+            content: Cow::Owned(String::from_utf8_lossy(content).into_owned()),
+        }
+    }
+}
+
+impl<'a> Widget for RawAnsi<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Text::from_ansi(&self.content).render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_applies_parsed_style() {
+        let widget = RawAnsi::new("\x1b[31mred\x1b[0m");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        widget.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["red"]);
+        expected.set_style(expected.area, Style::default().fg(Color::Red));
+        assert_eq!(buf, expected);
+        assert_eq!(buf.get(0, 0).style().fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn from_bytes_matches_new_for_valid_utf8() {
+        let widget = RawAnsi::from_bytes(b"\x1b[32mgreen\x1b[0m");
+        assert_eq!(widget, RawAnsi::new("\x1b[32mgreen\x1b[0m"));
+    }
+}
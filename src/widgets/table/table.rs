@@ -1,12 +1,109 @@
+use std::{collections::HashMap, fmt, rc::Rc};
+
+use cassowary::{
+    strength::{MEDIUM, REQUIRED, WEAK},
+    Expression, Solver, Variable,
+    WeightedRelation::{EQ, GE, LE},
+};
 use itertools::Itertools;
+use unicode_width::UnicodeWidthStr;
 
 use super::*;
 use crate::{
     layout::SegmentSize,
     prelude::*,
+    text::WrapOptions,
     widgets::{Block, StatefulWidget, Widget},
 };
 
+/// Selects the algorithm [`Table::get_columns_widths`] uses to resolve column widths.
+///
+/// See [`Table::column_sizing`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ColumnSizing {
+    /// Resolve column widths via [`Layout`], the same solver used by every other widget's area
+    /// splitting. This is the default.
+    #[default]
+    Layout,
+    /// Resolve column widths with a dedicated cassowary [`Solver`]: a `REQUIRED` constraint pins
+    /// the total to the available width, a constraint at the column's [`ColumnPriority`]
+    /// (`MEDIUM` by default) prefers each column's declared size, and `Min`/`Max` columns
+    /// contribute only `WEAK` bounds and a `WEAK` preferred size, whatever their priority. This
+    /// mirrors how the original tui-rs `Table` sized columns, and tends to shrink columns
+    /// proportionally under pressure instead of starving whichever one is declared last.
+    Cassowary,
+    /// Resolve column widths with a cassowary [`Solver`] that always grows columns to exactly
+    /// fill the available width once every column's own lower bound and preferred size are
+    /// satisfied: a `REQUIRED` constraint pins the total, a `REQUIRED` constraint enforces each
+    /// column's floor (its `Length`/`Fixed`/`Min`/`Percentage`/`Ratio` size; once the floors add
+    /// up to more than the available width, each floor that no longer fits is left out), and the
+    /// width left
+    /// over once every pinned column is subtracted is shared among the rest in proportion to
+    /// `weight`: `Min`/`Max` columns behave as weight `1`, and `Constraint::Proportional(weight)`
+    /// columns split it `weight_i / sum(weights)`, so leftover space is never dumped entirely on
+    /// whichever column happens to be declared last. Widths are rounded with the
+    /// largest-remainder method so they still sum to the available width. Unlike
+    /// [`ColumnSizing::Layout`] and [`ColumnSizing::Cassowary`], leftover space is never parked
+    /// unused on `Min`-constrained columns.
+    Expand,
+    /// Ignores [`Table::widths`] and auto-fits each column to its content instead, the way
+    /// comfy-table's "dynamic" arrangement does: a column's natural width is the widest display
+    /// width among its header, footer, and every row's cell in that column.
+    ///
+    /// If the natural widths all fit in the available space, each column gets exactly its
+    /// natural width and any leftover is handed to [`Table::segment_size`], same as
+    /// [`ColumnSizing::Layout`]. If they don't fit, columns are shrunk in rounds: each round
+    /// computes the fair share (`remaining_width / remaining_columns`) and locks in, at its full
+    /// natural width, every column that already fits within that share; the rest are re-divided
+    /// among themselves next round. Once no column is below its fair share, the still-oversized
+    /// columns are clamped to it and the leftover cell from integer division is handed out to the
+    /// widest of them first. Every column keeps a floor of one cell, taken back from the widest
+    /// columns so the total never exceeds the available width; if there are more columns than
+    /// available cells, the narrowest columns collapse to `0` instead.
+    ContentFit,
+}
+
+/// Priority a column's [`Constraint`] is solved with under [`ColumnSizing::Cassowary`], set via
+/// [`Table::widths_with_priority`]. Mirrors the cassowary strengths the original tui-rs `Table`
+/// solved with, before [`ColumnSizing::Cassowary`] hardcoded every column to the same priority.
+///
+/// Columns default to [`ColumnPriority::Medium`] when set through [`Table::widths`] instead, so
+/// existing tables keep behaving exactly as before.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ColumnPriority {
+    /// The most elastic priority: the first to give up width under pressure, and the one that
+    /// absorbs whatever width is left over once every stronger column is sized.
+    Weak,
+    /// The default priority: satisfied whenever a [`ColumnPriority::Required`] column doesn't
+    /// force it to give way.
+    #[default]
+    Medium,
+    /// Never violated while the available width can hold it, e.g. a fixed
+    /// [`Constraint::Length`] column keeps its exact size under pressure. A `Required` size that
+    /// can't fit alongside the total and earlier `Required` columns is dropped, and the column
+    /// takes whatever width the other columns leave. `Min`/`Max` columns are solved at `WEAK`
+    /// strength whatever their priority.
+    Required,
+}
+
+/// Selects how a row [`Cell`] handles text wider than its column's computed width.
+///
+/// See [`Table::cell_overflow`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum CellOverflow {
+    /// Clip overflowing text at the column boundary, mid-glyph if necessary. This is the
+    /// default, matching the renderer's historical behavior.
+    #[default]
+    Clip,
+    /// Truncate the cell's first line and append [`Table::overflow_marker`] (`"…"` by default),
+    /// trimming just enough to fit the marker's own display width.
+    Ellipsis,
+    /// Word-wrap overflowing text onto additional lines, the same way [`Table::row_height_auto`]
+    /// wraps every cell. Pair this with [`Table::row_height_auto`] so the extra lines aren't
+    /// immediately clipped by the row's height.
+    Wrap,
+}
+
 /// A widget to display data in formatted columns.
 ///
 /// A `Table` is a collection of [`Row`]s, each composed of [`Cell`]s:
@@ -45,12 +142,23 @@ use crate::{
 /// - [`Table::header`] sets the header row of the [`Table`].
 /// - [`Table::footer`] sets the footer row of the [`Table`].
 /// - [`Table::widths`] sets the width constraints of each column.
+/// - [`Table::widths_with_priority`] sets each column's width constraint together with the
+///   cassowary priority it's solved with under [`ColumnSizing::Cassowary`].
 /// - [`Table::column_spacing`] sets the spacing between each column.
 /// - [`Table::block`] wraps the table in a [`Block`] widget.
 /// - [`Table::style`] sets the base style of the widget.
 /// - [`Table::highlight_style`] sets the style of the selected row.
 /// - [`Table::highlight_symbol`] sets the symbol to be displayed in front of the selected row.
 /// - [`Table::highlight_spacing`] sets when to show the highlight spacing.
+/// - [`Table::row_height_auto`] wraps cell text to the column width and sizes each row to fit it.
+/// - [`Table::column_sizing`] selects the algorithm used to resolve column widths.
+/// - [`Table::freeze_first_column`] pins the first column in place while the rest scroll under it.
+/// - [`Table::highlight_column_style`] sets the style of the selected column.
+/// - [`Table::cell_highlight_style`] sets the style of the selected cell.
+/// - [`Table::column_alignments`] sets each column's default text alignment.
+/// - [`Table::column_alignment`] sets a single column's default text alignment.
+/// - [`Table::cell_overflow`] selects how a cell handles text wider than its column.
+/// - [`Table::overflow_marker`] sets the marker appended by [`CellOverflow::Ellipsis`].
 ///
 /// # Example
 ///
@@ -188,7 +296,7 @@ use crate::{
 ///
 /// frame.render_stateful_widget(table, area, &mut table_state);
 /// # }
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[derive(Clone)]
 pub struct Table<'a> {
     /// Data to display in each row
     rows: Vec<Row<'a>>,
@@ -202,9 +310,18 @@ pub struct Table<'a> {
     /// Width constraints for each column
     widths: Vec<Constraint>,
 
+    /// Per-column solve priority under [`ColumnSizing::Cassowary`], set via
+    /// [`Table::widths_with_priority`]. Columns with no entry (including everything set through
+    /// plain [`Table::widths`]) solve at [`ColumnPriority::Medium`].
+    column_priorities: Vec<Option<ColumnPriority>>,
+
     /// Space between each column
     column_spacing: u16,
 
+    /// Per-column default text alignment, applied to a column's header/row/footer [`Cell`]s that
+    /// don't already set their own [`Text::alignment`]
+    column_alignments: Vec<Option<Alignment>>,
+
     /// A block to wrap the widget in
     block: Option<Block<'a>>,
 
@@ -222,6 +339,63 @@ pub struct Table<'a> {
 
     /// Controls how to distribute extra space among the columns
     segment_size: SegmentSize,
+
+    /// Whether each row's height is computed by word-wrapping its cells to the column width,
+    /// instead of using the fixed [`Row::height`]
+    row_height_auto: bool,
+
+    /// Algorithm used to resolve column widths
+    column_sizing: ColumnSizing,
+
+    /// Whether the first column stays pinned at the left edge while the rest scroll underneath
+    /// it, once [`TableState::column_offset`] is non-zero
+    freeze_first_column: bool,
+
+    /// Style used to render the selected column, set via [`TableState::select_column`]
+    highlight_column_style: Style,
+
+    /// Style used to render the single cell at the intersection of the selected row and
+    /// [`TableState::selected_column`]
+    cell_highlight_style: Style,
+
+    /// How a row [`Cell`] handles text wider than its column
+    cell_overflow: CellOverflow,
+
+    /// Marker appended by [`CellOverflow::Ellipsis`]
+    overflow_marker: String,
+
+    /// Base style for each row, keyed by its index within [`Table::rows`]
+    ///
+    /// See [`Table::row_style_fn`] and [`Table::alternating_row_styles`].
+    row_style_fn: Option<Rc<dyn Fn(usize) -> Style>>,
+}
+
+impl<'a> fmt::Debug for Table<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Table")
+            .field("rows", &self.rows)
+            .field("header", &self.header)
+            .field("footer", &self.footer)
+            .field("widths", &self.widths)
+            .field("column_priorities", &self.column_priorities)
+            .field("column_spacing", &self.column_spacing)
+            .field("column_alignments", &self.column_alignments)
+            .field("block", &self.block)
+            .field("style", &self.style)
+            .field("highlight_style", &self.highlight_style)
+            .field("highlight_symbol", &self.highlight_symbol)
+            .field("highlight_spacing", &self.highlight_spacing)
+            .field("segment_size", &self.segment_size)
+            .field("row_height_auto", &self.row_height_auto)
+            .field("column_sizing", &self.column_sizing)
+            .field("freeze_first_column", &self.freeze_first_column)
+            .field("highlight_column_style", &self.highlight_column_style)
+            .field("cell_highlight_style", &self.cell_highlight_style)
+            .field("cell_overflow", &self.cell_overflow)
+            .field("overflow_marker", &self.overflow_marker)
+            .field("row_style_fn", &self.row_style_fn.as_ref().map(|_| ".."))
+            .finish()
+    }
 }
 
 impl<'a> Default for Table<'a> {
@@ -231,13 +405,23 @@ impl<'a> Default for Table<'a> {
             header: Default::default(),
             footer: Default::default(),
             widths: Default::default(),
+            column_priorities: Default::default(),
             column_spacing: 1,
+            column_alignments: Default::default(),
             block: Default::default(),
             style: Default::default(),
             highlight_style: Default::default(),
             highlight_symbol: Default::default(),
             highlight_spacing: Default::default(),
             segment_size: SegmentSize::None,
+            row_height_auto: false,
+            column_sizing: ColumnSizing::Layout,
+            freeze_first_column: false,
+            highlight_column_style: Default::default(),
+            cell_highlight_style: Default::default(),
+            cell_overflow: CellOverflow::Clip,
+            overflow_marker: "…".to_string(),
+            row_style_fn: None,
         }
     }
 }
@@ -391,6 +575,47 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Set the widths of the columns together with the [`ColumnPriority`] each is solved with
+    /// under [`ColumnSizing::Cassowary`].
+    ///
+    /// A [`ColumnPriority::Required`] column is never shrunk or grown away from its constraint
+    /// while the available width can hold it (see [`ColumnPriority::Required`]); `Min`/`Max`
+    /// columns are solved at `WEAK` strength whatever priority they're given. A
+    /// [`ColumnPriority::Weak`] one is the most elastic: it's the first to give up width when
+    /// `max_width` is tight, and it absorbs whatever width is left over once every
+    /// [`ColumnPriority::Medium`]/[`ColumnPriority::Required`] column has its own (what plain
+    /// [`Table::widths`] uses for every column, so existing tables are unaffected). This has no
+    /// effect under [`ColumnSizing::Layout`] or [`ColumnSizing::Expand`], which don't solve
+    /// priorities.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let table = Table::default()
+    ///     .column_sizing(ColumnSizing::Cassowary)
+    ///     .widths_with_priority([
+    ///         (Constraint::Length(10), ColumnPriority::Required),
+    ///         (Constraint::Percentage(30), ColumnPriority::Weak),
+    ///     ]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn widths_with_priority<I>(mut self, widths: I) -> Self
+    where
+        I: IntoIterator<Item = (Constraint, ColumnPriority)>,
+    {
+        let (widths, priorities): (Vec<Constraint>, Vec<Option<ColumnPriority>>) = widths
+            .into_iter()
+            .map(|(constraint, priority)| (constraint, Some(priority)))
+            .unzip();
+        ensure_percentages_less_than_100(&widths);
+        self.widths = widths;
+        self.column_priorities = priorities;
+        self
+    }
+
     /// Set the spacing between columns
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -409,6 +634,56 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Sets each column's default text [`Alignment`].
+    ///
+    /// This default is used whenever a header, row, or footer [`Cell`] in that column doesn't
+    /// already set its own [`Text::alignment`], so numeric columns can be right-aligned without
+    /// annotating every cell individually. Columns past the end of `alignments` keep whatever
+    /// alignment their cells already have.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).column_alignments([Alignment::Left, Alignment::Right]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_alignments<I>(mut self, alignments: I) -> Self
+    where
+        I: IntoIterator<Item = Alignment>,
+    {
+        self.column_alignments = alignments.into_iter().map(Some).collect();
+        self
+    }
+
+    /// Sets a single column's default text [`Alignment`], leaving the others untouched.
+    ///
+    /// See [`Table::column_alignments`] for how this default interacts with a [`Cell`]'s own
+    /// [`Text::alignment`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).column_alignment(1, Alignment::Right);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_alignment(mut self, index: usize, alignment: Alignment) -> Self {
+        if index >= self.column_alignments.len() {
+            self.column_alignments.resize(index + 1, None);
+        }
+        self.column_alignments[index] = Some(alignment);
+        self
+    }
+
     /// Wraps the table with a custom [`Block`] widget.
     ///
     /// The `block` parameter is of type [`Block`]. This holds the specified block to be
@@ -568,6 +843,232 @@ impl<'a> Table<'a> {
         self.segment_size = segment_size;
         self
     }
+
+    /// Sizes each row to fit its content instead of using a fixed [`Row::height`].
+    ///
+    /// When enabled, every [`Cell`]'s text is word-wrapped to its column's computed width (the
+    /// same wrapping [`Text::wrap`] does for a [`Paragraph`](crate::widgets::Paragraph)), and the
+    /// row is laid out at the height of its tallest wrapped cell. A row's explicit [`Row::height`]
+    /// is still honored as a minimum, so short rows aren't squashed, and columns with a computed
+    /// width of `0` are skipped rather than forcing every row down to one line.
+    ///
+    /// This lets free-form text be dropped into a table without pre-splitting it into lines.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).row_height_auto(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn row_height_auto(mut self, row_height_auto: bool) -> Self {
+        self.row_height_auto = row_height_auto;
+        self
+    }
+
+    /// Selects the algorithm used to resolve column widths from [`Table::widths`].
+    ///
+    /// [`ColumnSizing::Layout`] (the default) routes through the same [`Layout`] solver every
+    /// other widget uses to split areas. [`ColumnSizing::Cassowary`] resolves widths with its own
+    /// cassowary solver instead, which tends to shrink columns proportionally under pressure
+    /// rather than starving whichever one is declared last.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Min(5), Constraint::Min(5)];
+    /// let table = Table::new(rows, widths).column_sizing(ColumnSizing::Cassowary);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_sizing(mut self, column_sizing: ColumnSizing) -> Self {
+        self.column_sizing = column_sizing;
+        self
+    }
+
+    /// Pins the first column at the left edge while the rest of the columns scroll underneath it
+    /// as [`TableState::column_offset`] changes, the way the row header column of a spreadsheet
+    /// stays put while the sheet scrolls horizontally.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).freeze_first_column(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn freeze_first_column(mut self, freeze_first_column: bool) -> Self {
+        self.freeze_first_column = freeze_first_column;
+        self
+    }
+
+    /// Set the style of the column selected via [`TableState::select_column`]
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style is applied to every cell in the selected column, the same way
+    /// [`Table::highlight_style`] applies to every cell in the selected row, and the two compose
+    /// when a row and a column are selected at once.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).highlight_column_style(Style::new().red().italic());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_column_style<S: Into<Style>>(mut self, highlight_column_style: S) -> Self {
+        self.highlight_column_style = highlight_column_style.into();
+        self
+    }
+
+    /// Set the style of the single cell at [`TableState::selected_cell`]
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// Unlike [`Table::highlight_style`] and [`Table::highlight_column_style`], which shade the
+    /// whole selected row/column, this style is painted onto just the one cell where the selected
+    /// row and selected column intersect, after both of those styles are applied, so it composes
+    /// on top of them. This is what lets grid-style tables distinguish the active cell from the
+    /// rest of the selected row and column.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).cell_highlight_style(Style::new().reversed());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn cell_highlight_style<S: Into<Style>>(mut self, cell_highlight_style: S) -> Self {
+        self.cell_highlight_style = cell_highlight_style.into();
+        self
+    }
+
+    /// Selects how a row [`Cell`] handles text wider than its column's computed width.
+    ///
+    /// [`CellOverflow::Clip`] (the default) cuts the text off at the column boundary. See
+    /// [`CellOverflow`] for the other policies.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).cell_overflow(CellOverflow::Ellipsis);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn cell_overflow(mut self, cell_overflow: CellOverflow) -> Self {
+        self.cell_overflow = cell_overflow;
+        self
+    }
+
+    /// Sets the marker [`CellOverflow::Ellipsis`] appends to a truncated cell. Defaults to `"…"`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .cell_overflow(CellOverflow::Ellipsis)
+    ///     .overflow_marker("...");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn overflow_marker<T: Into<String>>(mut self, overflow_marker: T) -> Self {
+        self.overflow_marker = overflow_marker.into();
+        self
+    }
+
+    /// Sets a callback that returns a base [`Style`] for each row, keyed by its index within
+    /// [`Table::rows`] (not affected by scrolling or [`TableState::offset`]).
+    ///
+    /// The returned style is patched underneath the row's explicit [`Row::style`] and each
+    /// [`Cell`]'s own style, so any style set there still wins, and [`Table::highlight_style`]
+    /// still overrides everything on the selected row. See [`Table::alternating_row_styles`] for
+    /// the common case of zebra-striping every other row.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .row_style_fn(|index| {
+    ///         if index % 2 == 0 {
+    ///             Style::default()
+    ///         } else {
+    ///             Style::new().bg(Color::DarkGray)
+    ///         }
+    ///     });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn row_style_fn<F>(mut self, row_style_fn: F) -> Self
+    where
+        F: Fn(usize) -> Style + 'static,
+    {
+        self.row_style_fn = Some(Rc::new(row_style_fn));
+        self
+    }
+
+    /// Shades every other data row with `odd`, starting from `even` on the first row, a common
+    /// readability aid for dense tables.
+    ///
+    /// This is a convenience wrapper around [`Table::row_style_fn`], and follows the same
+    /// style-cascade rules: explicit [`Row`]/[`Cell`] styles and [`Table::highlight_style`] still
+    /// take precedence over it.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .alternating_row_styles(Style::default(), Style::new().bg(Color::DarkGray));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn alternating_row_styles(self, even: Style, odd: Style) -> Self {
+        self.row_style_fn(move |index| if index % 2 == 0 { even } else { odd })
+    }
+
+    /// Base style for row `index`, from [`Table::row_style_fn`]/[`Table::alternating_row_styles`]
+    fn row_style(&self, index: usize) -> Option<Style> {
+        self.row_style_fn
+            .as_ref()
+            .map(|row_style_fn| row_style_fn(index))
+    }
 }
 
 impl Widget for Table<'_> {
@@ -588,10 +1089,10 @@ impl StatefulWidget for Table<'_> {
             return;
         }
         let selection_width = self.selection_width(state);
-        let columns_widths = self.get_columns_widths(table_area.width, selection_width);
+        let columns = self.visible_columns(table_area.width, selection_width, state);
         let (header_area, rows_area, footer_area) = self.layout(table_area);
 
-        self.render_header(header_area, buf, &columns_widths);
+        self.render_header(header_area, buf, &columns);
 
         self.render_rows(
             rows_area,
@@ -599,10 +1100,10 @@ impl StatefulWidget for Table<'_> {
             state,
             selection_width,
             &self.highlight_symbol,
-            &columns_widths,
+            &columns,
         );
 
-        self.render_footer(footer_area, buf, columns_widths);
+        self.render_footer(footer_area, buf, &columns);
     }
 }
 
@@ -640,22 +1141,92 @@ impl Table<'_> {
         }
     }
 
-    fn render_header(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
+    fn render_header(&self, area: Rect, buf: &mut Buffer, columns: &[(usize, u16, u16)]) {
         if let Some(ref header) = self.header {
             buf.set_style(area, header.style);
-            for ((x, width), cell) in column_widths.iter().zip(header.cells.iter()) {
-                cell.render(Rect::new(area.x + x, area.y, *width, area.height), buf);
+            for &(col, x, width) in columns {
+                if let Some(cell) = header.cells.get(col) {
+                    self.render_cell(
+                        cell,
+                        col,
+                        Rect::new(area.x + x, area.y, width, area.height),
+                        buf,
+                    );
+                }
             }
         }
     }
 
-    fn render_footer(&self, area: Rect, buf: &mut Buffer, column_widths: Vec<(u16, u16)>) {
+    fn render_footer(&self, area: Rect, buf: &mut Buffer, columns: &[(usize, u16, u16)]) {
         if let Some(ref footer) = self.footer {
             buf.set_style(area, footer.style);
-            for ((x, width), cell) in column_widths.iter().zip(footer.cells.iter()) {
-                cell.render(Rect::new(area.x + x, area.y, *width, area.height), buf);
+            for &(col, x, width) in columns {
+                if let Some(cell) = footer.cells.get(col) {
+                    self.render_cell(
+                        cell,
+                        col,
+                        Rect::new(area.x + x, area.y, width, area.height),
+                        buf,
+                    );
+                }
+            }
+        }
+    }
+
+    /// This column's default [`Alignment`] from [`Table::column_alignments`], if set.
+    fn column_alignment(&self, col: usize) -> Option<Alignment> {
+        self.column_alignments.get(col).copied().flatten()
+    }
+
+    /// This column's [`ColumnPriority`] from [`Table::widths_with_priority`], defaulting to
+    /// [`ColumnPriority::Medium`] when unset.
+    fn column_priority(&self, col: usize) -> ColumnPriority {
+        self.column_priorities
+            .get(col)
+            .copied()
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    /// Renders `cell` into `area`, falling back to [`Table::column_alignment`] when the cell's
+    /// own [`Text::alignment`] hasn't already set one.
+    fn render_cell(&self, cell: &Cell, col: usize, area: Rect, buf: &mut Buffer) {
+        if cell.content.alignment.is_none() {
+            if let Some(alignment) = self.column_alignment(col) {
+                Cell::from(cell.content.clone().alignment(alignment))
+                    .style(cell.style)
+                    .render(area, buf);
+                return;
             }
         }
+        cell.render(area, buf);
+    }
+
+    /// Renders `cell` into `area` as [`Table::render_cell`] does, additionally applying
+    /// [`CellOverflow::Ellipsis`] to the cell's first line when it's wider than `area` and the
+    /// marker itself still leaves room for at least one column of content.
+    fn render_cell_with_overflow(&self, cell: &Cell, col: usize, area: Rect, buf: &mut Buffer) {
+        if self.cell_overflow != CellOverflow::Ellipsis || area.width == 0 {
+            self.render_cell(cell, col, area, buf);
+            return;
+        }
+        let marker_width = UnicodeWidthStr::width(self.overflow_marker.as_str()) as u16;
+        let overflows = cell.content.width() > area.width as usize;
+        if !overflows || marker_width >= area.width {
+            self.render_cell(cell, col, area, buf);
+            return;
+        }
+        let content_area = Rect {
+            width: area.width - marker_width,
+            ..area
+        };
+        self.render_cell(cell, col, content_area, buf);
+        buf.set_string(
+            area.x + area.width - marker_width,
+            area.y,
+            &self.overflow_marker,
+            cell.style,
+        );
     }
 
     fn render_rows(
@@ -665,14 +1236,15 @@ impl Table<'_> {
         state: &mut TableState,
         selection_width: u16,
         highlight_symbol: &Text<'_>,
-        columns_widths: &[(u16, u16)],
+        columns: &[(usize, u16, u16)],
     ) {
         if self.rows.is_empty() {
             return;
         }
 
+        let row_heights = self.row_heights(columns);
         let (start_index, end_index) =
-            self.get_row_bounds(state.selected, state.offset, area.height);
+            self.get_row_bounds(state.selected, state.offset, area.height, &row_heights);
         state.offset = start_index;
 
         let mut y_offset = 0;
@@ -683,12 +1255,16 @@ impl Table<'_> {
             .skip(state.offset)
             .take(end_index - start_index)
         {
+            let (_, height_with_margin) = row_heights[i];
             let row_area = Rect::new(
                 area.x,
                 area.y + y_offset + row.top_margin,
                 area.width,
-                row.height_with_margin() - row.top_margin,
+                height_with_margin - row.top_margin,
             );
+            if let Some(row_style) = self.row_style(i) {
+                buf.set_style(row_area, row_style);
+            }
             buf.set_style(row_area, row.style);
 
             let is_selected = state.selected().is_some_and(|index| index == i);
@@ -700,85 +1276,581 @@ impl Table<'_> {
                 buf.set_style(selection_area, row.style);
                 highlight_symbol.clone().render(selection_area, buf);
             };
-            for ((x, width), cell) in columns_widths.iter().zip(row.cells.iter()) {
-                cell.render(
-                    Rect::new(row_area.x + x, row_area.y, *width, row_area.height),
-                    buf,
-                );
+            for &(col, x, width) in columns {
+                let Some(cell) = row.cells.get(col) else {
+                    continue;
+                };
+                let cell_area = Rect::new(row_area.x + x, row_area.y, width, row_area.height);
+                if width > 0 && (self.row_height_auto || self.cell_overflow == CellOverflow::Wrap) {
+                    // Render the wrapped text directly rather than `cell`, so the extra height
+                    // `row_heights` computed is actually filled with the rest of the cell's text
+                    // instead of leaving it blank below the first line.
+                    let mut content = cell.content.wrap(width, WrapOptions::new().break_words(true));
+                    if content.alignment.is_none() {
+                        content.alignment = self.column_alignment(col);
+                    }
+                    Cell::from(content).style(cell.style).render(cell_area, buf);
+                } else {
+                    self.render_cell_with_overflow(cell, col, cell_area, buf);
+                }
+                if state.selected_column() == Some(col) {
+                    buf.set_style(cell_area, self.highlight_column_style);
+                }
             }
             if is_selected {
                 buf.set_style(row_area, self.highlight_style);
+                if let Some(selected_col) = state.selected_column() {
+                    if let Some(&(_, x, width)) =
+                        columns.iter().find(|&&(col, ..)| col == selected_col)
+                    {
+                        let cell_area =
+                            Rect::new(row_area.x + x, row_area.y, width, row_area.height);
+                        buf.set_style(cell_area, self.cell_highlight_style);
+                    }
+                }
             }
-            y_offset += row.height_with_margin();
+            y_offset += height_with_margin;
         }
     }
 
+    /// Per-row `(height, height_with_margin)`, substituting [`auto_row_height`] for the row's
+    /// explicit height when [`Table::row_height_auto`] is enabled.
+    fn row_heights(&self, columns: &[(usize, u16, u16)]) -> Vec<(u16, u16)> {
+        self.rows
+            .iter()
+            .map(|row| {
+                let height = if self.row_height_auto {
+                    auto_row_height(row, columns)
+                } else {
+                    row.height
+                };
+                (height, height + row.top_margin + row.bottom_margin)
+            })
+            .collect()
+    }
+
     /// Get all offsets and widths of all user specified columns.
     ///
     /// Returns (x, width). When self.widths is empty, it is assumed `.widths()` has not been called
     /// and a default of equal widths is returned.
     fn get_columns_widths(&self, max_width: u16, selection_width: u16) -> Vec<(u16, u16)> {
-        let widths = if self.widths.is_empty() {
-            let col_count = self
-                .rows
-                .iter()
-                .chain(self.header.iter())
-                .chain(self.footer.iter())
-                .map(|r| r.cells.len())
-                .max()
-                .unwrap_or(0);
-            // Divide the space between each column equally
-            vec![Constraint::Length(max_width / col_count.max(1) as u16); col_count]
-        } else {
-            self.widths.to_vec()
-        };
-        // this will always allocate a selection area
-        let [_selection_area, columns_area] =
-            Rect::new(0, 0, max_width, 1).split(&Layout::horizontal([
-                Constraint::Fixed(selection_width),
+        let widths = self.resolved_widths(max_width);
+        match self.column_sizing {
+            ColumnSizing::Layout => {
+                // this will always allocate a selection area
+                let [_selection_area, columns_area] =
+                    Rect::new(0, 0, max_width, 1).split(&Layout::horizontal([
+                        Constraint::Fixed(selection_width),
+                        Constraint::Proportional(0),
+                    ]));
+                #[allow(deprecated)]
+                let rects = Layout::horizontal(widths)
+                    .segment_size(self.segment_size)
+                    .spacing(self.column_spacing)
+                    .split(columns_area);
+                rects.iter().map(|c| (c.x, c.width)).collect()
+            }
+            ColumnSizing::Cassowary => {
+                self.get_columns_widths_cassowary(max_width, selection_width, &widths)
+            }
+            ColumnSizing::Expand => {
+                self.get_columns_widths_expand(max_width, selection_width, &widths)
+            }
+            ColumnSizing::ContentFit => {
+                self.get_columns_widths_content_fit(max_width, selection_width, widths.len())
+            }
+        }
+    }
+
+    /// Returns `(original_column_index, x, width)` for each column that should actually be
+    /// drawn: [`Table::get_columns_widths`] lays out every column, and this culls whatever
+    /// `column_offset` (and [`Table::freeze_first_column`]) scroll out of view, then repacks the
+    /// rest flush against the selection column so hidden columns don't leave a gap.
+    fn visible_columns(
+        &self,
+        max_width: u16,
+        selection_width: u16,
+        state: &mut TableState,
+    ) -> Vec<(usize, u16, u16)> {
+        let widths = self.get_columns_widths(max_width, selection_width);
+        let columns_width = max_width.saturating_sub(selection_width);
+        let column_widths_with_spacing: Vec<(u16, u16)> = widths
+            .iter()
+            .map(|&(_, width)| (width, width + self.column_spacing))
+            .collect();
+        let (start, end) = self.get_column_bounds(
+            state.selected_column(),
+            state.column_offset(),
+            columns_width,
+            &column_widths_with_spacing,
+        );
+        state.column_offset = start;
+        let indices = self.visible_column_indices(widths.len(), start, end);
+        let mut x = selection_width;
+        indices
+            .into_iter()
+            .map(|col| {
+                let (_, width) = widths[col];
+                let result = (col, x, width);
+                x += width + self.column_spacing;
+                result
+            })
+            .collect()
+    }
+
+    /// Indices of the columns that remain visible once `column_offset` scrolls the rest out of
+    /// view, capped at `end` (the exclusive index past the last column that fits in `max_width`,
+    /// as computed by [`Table::get_column_bounds`]). When [`Table::freeze_first_column`] is set,
+    /// column `0` is always kept, pinned ahead of the scrolled window.
+    fn visible_column_indices(
+        &self,
+        col_count: usize,
+        column_offset: usize,
+        end: usize,
+    ) -> Vec<usize> {
+        if col_count == 0 {
+            return vec![];
+        }
+        let end = end.min(col_count);
+        if self.freeze_first_column && col_count > 1 {
+            let column_offset = column_offset.min(col_count - 2);
+            let start = (1 + column_offset).min(end);
+            std::iter::once(0).chain(start..end).collect()
+        } else {
+            let column_offset = column_offset.min(col_count.saturating_sub(1));
+            let start = column_offset.min(end);
+            (start..end).collect()
+        }
+    }
+
+    /// Returns the constraint for each column, falling back to equal [`Constraint::Length`]s
+    /// sized from the widest row/header/footer when [`Table::widths`] hasn't been called.
+    fn resolved_widths(&self, max_width: u16) -> Vec<Constraint> {
+        if self.widths.is_empty() {
+            let col_count = self
+                .rows
+                .iter()
+                .chain(self.header.iter())
+                .chain(self.footer.iter())
+                .map(|r| r.cells.len())
+                .max()
+                .unwrap_or(0);
+            // Divide the space between each column equally
+            vec![Constraint::Length(max_width / col_count.max(1) as u16); col_count]
+        } else {
+            self.widths.to_vec()
+        }
+    }
+
+    /// Resolves column widths with a cassowary [`Solver`] instead of [`Layout`]. See
+    /// [`ColumnSizing::Cassowary`].
+    fn get_columns_widths_cassowary(
+        &self,
+        max_width: u16,
+        selection_width: u16,
+        widths: &[Constraint],
+    ) -> Vec<(u16, u16)> {
+        let col_count = widths.len();
+        if col_count == 0 {
+            return vec![];
+        }
+
+        let columns_width = max_width.saturating_sub(selection_width);
+        let spacing_total = self
+            .column_spacing
+            .saturating_mul(col_count.saturating_sub(1) as u16);
+        let available = f64::from(columns_width.saturating_sub(spacing_total));
+
+        let variables: Vec<Variable> = (0..col_count).map(|_| Variable::new()).collect();
+        let mut solver = Solver::new();
+
+        let total = variables
+            .iter()
+            .fold(Expression::from_constant(0.0), |acc, &v| acc + v);
+        solver
+            .add_constraint(total | EQ(REQUIRED) | available)
+            .expect("the total column width is the first constraint added, so it always solves");
+        for &variable in &variables {
+            solver
+                .add_constraint(variable | GE(REQUIRED) | 0.0)
+                .expect("non-negativity bounds always solve alongside a non-negative total");
+        }
+
+        for (col, (&variable, constraint)) in variables.iter().zip(widths).enumerate() {
+            let preferred = match *constraint {
+                Constraint::Length(n) | Constraint::Fixed(n) => f64::from(n),
+                Constraint::Percentage(p) => available * f64::from(p) / 100.0,
+                Constraint::Ratio(num, den) => available * f64::from(num) / f64::from(den.max(1)),
+                Constraint::Proportional(_) => available / col_count as f64,
+                Constraint::Min(n) => {
+                    let _ = solver.add_constraint(variable | GE(WEAK) | f64::from(n));
+                    f64::from(n)
+                }
+                Constraint::Max(n) => {
+                    let _ = solver.add_constraint(variable | LE(WEAK) | f64::from(n));
+                    f64::from(n)
+                }
+            };
+            // Columns are added to the solver in left-to-right order, so ties between columns at
+            // the same priority resolve deterministically by column index rather than arbitrarily.
+            //
+            // `Min`/`Max` are meant to be the elastic columns that absorb leftover width
+            // (mirroring the original tui-rs behavior), so their preferred size is only ever a
+            // WEAK suggestion, regardless of the column's configured priority.
+            let strength = match *constraint {
+                Constraint::Min(_) | Constraint::Max(_) => WEAK,
+                _ => match self.column_priority(col) {
+                    ColumnPriority::Weak => WEAK,
+                    ColumnPriority::Medium => MEDIUM,
+                    ColumnPriority::Required => REQUIRED,
+                },
+            };
+            let _ = solver.add_constraint(variable | EQ(strength) | preferred);
+        }
+
+        let mut values: HashMap<Variable, f64> = HashMap::with_capacity(col_count);
+        values.extend(solver.fetch_changes().iter().copied());
+
+        let mut x = selection_width;
+        variables
+            .iter()
+            .map(|variable| {
+                let width = values.get(variable).copied().unwrap_or(0.0).max(0.0).round() as u16;
+                let result = (x, width);
+                x += width + self.column_spacing;
+                result
+            })
+            .collect()
+    }
+
+    /// Resolves column widths with a cassowary [`Solver`] that grows columns to exactly fill the
+    /// available width. See [`ColumnSizing::Expand`].
+    fn get_columns_widths_expand(
+        &self,
+        max_width: u16,
+        selection_width: u16,
+        widths: &[Constraint],
+    ) -> Vec<(u16, u16)> {
+        let col_count = widths.len();
+        if col_count == 0 {
+            return vec![];
+        }
+
+        let columns_width = max_width.saturating_sub(selection_width);
+        let spacing_total = self
+            .column_spacing
+            .saturating_mul(col_count.saturating_sub(1) as u16);
+        let available = f64::from(columns_width.saturating_sub(spacing_total));
+
+        let variables: Vec<Variable> = (0..col_count).map(|_| Variable::new()).collect();
+        let mut solver = Solver::new();
+
+        let total = variables
+            .iter()
+            .fold(Expression::from_constant(0.0), |acc, &v| acc + v);
+        solver
+            .add_constraint(total | EQ(REQUIRED) | available)
+            .expect("the total column width is the first constraint added, so it always solves");
+        for &variable in &variables {
+            solver
+                .add_constraint(variable | GE(REQUIRED) | 0.0)
+                .expect("non-negativity bounds always solve alongside a non-negative total");
+        }
+
+        let mut pinned_total = 0.0;
+        let mut growable: Vec<(Variable, f64)> = Vec::new();
+        for (&variable, constraint) in variables.iter().zip(widths) {
+            let floor = match *constraint {
+                Constraint::Length(n) | Constraint::Fixed(n) | Constraint::Min(n) => f64::from(n),
+                Constraint::Percentage(p) => available * f64::from(p) / 100.0,
+                Constraint::Ratio(num, den) => available * f64::from(num) / f64::from(den.max(1)),
+                Constraint::Proportional(_) | Constraint::Max(_) => 0.0,
+            };
+            // A floor that can't be met alongside the total and the floors of earlier columns is
+            // rejected by the solver and left out.
+            let _ = solver.add_constraint(variable | GE(REQUIRED) | floor);
+
+            match *constraint {
+                Constraint::Length(n) | Constraint::Fixed(n) => {
+                    pinned_total += f64::from(n);
+                    let _ = solver.add_constraint(variable | EQ(MEDIUM) | f64::from(n));
+                }
+                Constraint::Percentage(_) | Constraint::Ratio(..) => {
+                    pinned_total += floor;
+                    let _ = solver.add_constraint(variable | EQ(MEDIUM) | floor);
+                }
+                Constraint::Max(n) => {
+                    let _ = solver.add_constraint(variable | LE(WEAK) | f64::from(n));
+                    growable.push((variable, 1.0));
+                }
+                Constraint::Min(_) => growable.push((variable, 1.0)),
+                // `Proportional(weight)` shares the remaining width in proportion to `weight`,
+                // the same way a weighted chunk layout would; `Min`/`Max` columns with no
+                // explicit weight are treated as weight `1`, so they still split any leftover
+                // space evenly among themselves.
+                Constraint::Proportional(weight) => {
+                    growable.push((variable, f64::from(weight.max(1))));
+                }
+            }
+        }
+        let remaining = (available - pinned_total).max(0.0);
+        let total_weight: f64 = growable.iter().map(|&(_, weight)| weight).sum();
+        for &(variable, weight) in &growable {
+            let share = if total_weight > 0.0 {
+                remaining * weight / total_weight
+            } else {
+                0.0
+            };
+            let _ = solver.add_constraint(variable | EQ(MEDIUM) | share);
+        }
+
+        let mut values: HashMap<Variable, f64> = HashMap::with_capacity(col_count);
+        values.extend(solver.fetch_changes().iter().copied());
+        let raw: Vec<f64> = variables
+            .iter()
+            .map(|variable| values.get(variable).copied().unwrap_or(0.0).max(0.0))
+            .collect();
+
+        // Largest-remainder rounding: take each column's integer floor, then hand the leftover
+        // units from rounding to the columns with the biggest fractional remainder first, so the
+        // parts still sum to the whole available width.
+        let mut rounded: Vec<u16> = raw.iter().map(|&w| w.floor() as u16).collect();
+        let total_floor: u16 = rounded.iter().sum();
+        let mut remainder = (available.round() as u16).saturating_sub(total_floor);
+        let mut by_fraction: Vec<usize> = (0..col_count).collect();
+        by_fraction.sort_by(|&a, &b| {
+            let frac = |i: usize| raw[i] - raw[i].floor();
+            frac(b)
+                .partial_cmp(&frac(a))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        for index in by_fraction {
+            if remainder == 0 {
+                break;
+            }
+            rounded[index] += 1;
+            remainder -= 1;
+        }
+
+        let mut x = selection_width;
+        rounded
+            .into_iter()
+            .map(|width| {
+                let result = (x, width);
+                x += width + self.column_spacing;
+                result
+            })
+            .collect()
+    }
+
+    /// Each column's natural width for [`ColumnSizing::ContentFit`]: the widest display width
+    /// among its header, footer, and every row's cell in that column. Columns with no cells at
+    /// all (an out-of-range index) get a floor of `1`.
+    fn natural_column_widths(&self, col_count: usize) -> Vec<u16> {
+        (0..col_count)
+            .map(|col| {
+                self.header
+                    .iter()
+                    .chain(self.footer.iter())
+                    .chain(self.rows.iter())
+                    .filter_map(|row| row.cells.get(col))
+                    .map(|cell| cell.content.width() as u16)
+                    .max()
+                    .unwrap_or(0)
+                    .max(1)
+            })
+            .collect()
+    }
+
+    /// Resolves column widths by auto-fitting to content. See [`ColumnSizing::ContentFit`].
+    fn get_columns_widths_content_fit(
+        &self,
+        max_width: u16,
+        selection_width: u16,
+        col_count: usize,
+    ) -> Vec<(u16, u16)> {
+        if col_count == 0 {
+            return vec![];
+        }
+
+        let columns_width = max_width.saturating_sub(selection_width);
+        let spacing_total = self
+            .column_spacing
+            .saturating_mul(col_count.saturating_sub(1) as u16);
+        let available = columns_width.saturating_sub(spacing_total);
+
+        let natural = self.natural_column_widths(col_count);
+        let total_natural: u32 = natural.iter().map(|&w| u32::from(w)).sum();
+        let fitted = if total_natural <= u32::from(available) {
+            natural
+        } else {
+            Self::shrink_to_fair_share(&natural, available)
+        };
+
+        // Leftover between the fitted widths and `available` (only possible when everything
+        // fit) is handed to the same `Layout`/`segment_size` machinery `ColumnSizing::Layout`
+        // uses, rather than reimplementing that distribution here.
+        let [_selection_area, columns_area] =
+            Rect::new(0, 0, max_width, 1).split(&Layout::horizontal([
+                Constraint::Fixed(selection_width),
                 Constraint::Proportional(0),
             ]));
+        let constraints: Vec<Constraint> =
+            fitted.iter().map(|&width| Constraint::Fixed(width)).collect();
         #[allow(deprecated)]
-        let rects = Layout::horizontal(widths)
+        let rects = Layout::horizontal(constraints)
             .segment_size(self.segment_size)
             .spacing(self.column_spacing)
             .split(columns_area);
         rects.iter().map(|c| (c.x, c.width)).collect()
     }
 
+    /// comfy-table's shrink loop: repeatedly compute the fair share of `available` among the
+    /// columns not yet locked in, lock in (at full natural width) any column that already fits
+    /// within that share, and re-divide the leftover among the rest. Once every remaining column
+    /// is still over its fair share, clamp them all to it and floor-distribute the leftover cell
+    /// from integer division to the widest columns first.
+    fn shrink_to_fair_share(natural: &[u16], available: u16) -> Vec<u16> {
+        let col_count = natural.len();
+        let mut widths = vec![0u16; col_count];
+        let mut locked = vec![false; col_count];
+        let mut locked_total: u32 = 0;
+        let mut remaining = col_count;
+
+        while remaining > 0 {
+            let remaining_width = u32::from(available).saturating_sub(locked_total);
+            let fair_share = remaining_width / remaining as u32;
+            let mut locked_any = false;
+            for i in 0..col_count {
+                if !locked[i] && u32::from(natural[i]) <= fair_share {
+                    widths[i] = natural[i];
+                    locked[i] = true;
+                    locked_total += u32::from(natural[i]);
+                    remaining -= 1;
+                    locked_any = true;
+                }
+            }
+            if !locked_any {
+                let share = (remaining_width / remaining as u32) as u16;
+                let residual = remaining_width % remaining as u32;
+                let mut oversized: Vec<usize> = (0..col_count).filter(|&i| !locked[i]).collect();
+                oversized.sort_by_key(|&i| std::cmp::Reverse(natural[i]));
+                for (rank, &i) in oversized.iter().enumerate() {
+                    widths[i] = share + u16::from((rank as u32) < residual);
+                }
+                break;
+            }
+        }
+
+        // Every column before this point sums to exactly `available` (the main loop above hands
+        // out `remaining_width` in full each time it locks a column in or breaks out), so simply
+        // flooring every zero-width column to 1 would push the total over `available`. Instead,
+        // reclaim one cell per zero-width column from the widest columns first -- they have the
+        // most width to spare without dropping below the floor themselves -- and only raise as
+        // many zero-width columns as could actually be funded that way; if there isn't enough
+        // width to spare, the rest stay at 0 rather than the total overflowing `available`.
+        let zero_indices: Vec<usize> = (0..col_count).filter(|&i| widths[i] == 0).collect();
+        if !zero_indices.is_empty() {
+            let budget = zero_indices.len() as u32;
+            let mut donors: Vec<usize> = (0..col_count).collect();
+            donors.sort_by_key(|&i| std::cmp::Reverse(widths[i]));
+            let mut reclaimed = 0;
+            for i in donors {
+                if reclaimed >= budget {
+                    break;
+                }
+                let spare = u32::from(widths[i]).saturating_sub(1);
+                let take = spare.min(budget - reclaimed);
+                widths[i] -= take as u16;
+                reclaimed += take;
+            }
+            for &i in zero_indices.iter().take(reclaimed as usize) {
+                widths[i] = 1;
+            }
+        }
+        widths
+    }
+
     fn get_row_bounds(
         &self,
         selected: Option<usize>,
         offset: usize,
         max_height: u16,
+        row_heights: &[(u16, u16)],
     ) -> (usize, usize) {
         let offset = offset.min(self.rows.len().saturating_sub(1));
         let mut start = offset;
         let mut end = offset;
         let mut height = 0;
-        for item in self.rows.iter().skip(offset) {
-            if height + item.height > max_height {
+        for &(item_height, height_with_margin) in row_heights.iter().skip(offset) {
+            if height + item_height > max_height {
                 break;
             }
-            height += item.height_with_margin();
+            height += height_with_margin;
             end += 1;
         }
 
         let selected = selected.unwrap_or(0).min(self.rows.len() - 1);
         while selected >= end {
-            height = height.saturating_add(self.rows[end].height_with_margin());
+            height = height.saturating_add(row_heights[end].1);
             end += 1;
             while height > max_height {
-                height = height.saturating_sub(self.rows[start].height_with_margin());
+                height = height.saturating_sub(row_heights[start].1);
                 start += 1;
             }
         }
         while selected < start {
             start -= 1;
-            height = height.saturating_add(self.rows[start].height_with_margin());
+            height = height.saturating_add(row_heights[start].1);
             while height > max_height {
                 end -= 1;
-                height = height.saturating_sub(self.rows[end].height_with_margin());
+                height = height.saturating_sub(row_heights[end].1);
+            }
+        }
+        (start, end)
+    }
+
+    /// Mirrors [`Table::get_row_bounds`] horizontally: given the selected column and the current
+    /// [`TableState::column_offset`], returns the index of the first and one-past-the-last
+    /// column that fit within `max_width`, expanding or shifting the window so the selected
+    /// column always stays on screen.
+    fn get_column_bounds(
+        &self,
+        selected: Option<usize>,
+        offset: usize,
+        max_width: u16,
+        column_widths: &[(u16, u16)],
+    ) -> (usize, usize) {
+        if column_widths.is_empty() {
+            return (0, 0);
+        }
+        let offset = offset.min(column_widths.len() - 1);
+        let mut start = offset;
+        let mut end = offset;
+        let mut width = 0;
+        for &(item_width, width_with_spacing) in column_widths.iter().skip(offset) {
+            if width + item_width > max_width {
+                break;
+            }
+            width += width_with_spacing;
+            end += 1;
+        }
+
+        let selected = selected.unwrap_or(0).min(column_widths.len() - 1);
+        while selected >= end {
+            width = width.saturating_add(column_widths[end].1);
+            end += 1;
+            while width > max_width {
+                width = width.saturating_sub(column_widths[start].1);
+                start += 1;
+            }
+        }
+        while selected < start {
+            start -= 1;
+            width = width.saturating_add(column_widths[start].1);
+            while width > max_width {
+                end -= 1;
+                width = width.saturating_sub(column_widths[end].1);
             }
         }
         (start, end)
@@ -796,6 +1868,24 @@ impl Table<'_> {
     }
 }
 
+/// The effective height of `row` under [`Table::row_height_auto`]: each cell is word-wrapped to
+/// its column's width and the tallest one wins, never shrinking below the row's explicit
+/// [`Row::height`]. A column with a computed width of `0` is skipped, so a collapsed column can't
+/// force every row down to fit a single word.
+fn auto_row_height(row: &Row, columns: &[(usize, u16, u16)]) -> u16 {
+    columns
+        .iter()
+        .filter_map(|&(col, _, width)| row.cells.get(col).map(|cell| (cell, width)))
+        .filter(|(_, width)| *width > 0)
+        .map(|(cell, width)| {
+            let options = WrapOptions::new().break_words(true);
+            cell.content.wrap(width, options).height() as u16
+        })
+        .max()
+        .unwrap_or(0)
+        .max(row.height)
+}
+
 fn ensure_percentages_less_than_100(widths: &[Constraint]) {
     widths.iter().for_each(|&w| {
         if let Constraint::Percentage(p) = w {
@@ -861,6 +1951,14 @@ mod tests {
         assert_eq!(table.highlight_symbol, Text::default());
         assert_eq!(table.highlight_spacing, HighlightSpacing::WhenSelected);
         assert_eq!(table.segment_size, SegmentSize::None);
+        assert!(!table.row_height_auto);
+        assert_eq!(table.column_sizing, ColumnSizing::Layout);
+        assert!(!table.freeze_first_column);
+        assert_eq!(table.highlight_column_style, Style::default());
+        assert_eq!(table.column_alignments, vec![]);
+        assert_eq!(table.cell_overflow, CellOverflow::Clip);
+        assert_eq!(table.overflow_marker, "…");
+        assert!(table.row_style_fn.is_none());
     }
 
     #[test]
@@ -877,6 +1975,14 @@ mod tests {
         assert_eq!(table.highlight_symbol, Text::default());
         assert_eq!(table.highlight_spacing, HighlightSpacing::WhenSelected);
         assert_eq!(table.segment_size, SegmentSize::None);
+        assert!(!table.row_height_auto);
+        assert_eq!(table.column_sizing, ColumnSizing::Layout);
+        assert!(!table.freeze_first_column);
+        assert_eq!(table.highlight_column_style, Style::default());
+        assert_eq!(table.column_alignments, vec![]);
+        assert_eq!(table.cell_overflow, CellOverflow::Clip);
+        assert_eq!(table.overflow_marker, "…");
+        assert!(table.row_style_fn.is_none());
     }
 
     #[test]
@@ -975,6 +2081,199 @@ mod tests {
         let _ = Table::default().widths([Constraint::Percentage(110)]);
     }
 
+    #[test]
+    fn row_height_auto() {
+        let table = Table::default().row_height_auto(true);
+        assert!(table.row_height_auto);
+    }
+
+    #[test]
+    fn column_alignments() {
+        let table = Table::default().column_alignments([Alignment::Left, Alignment::Right]);
+        assert_eq!(
+            table.column_alignments,
+            vec![Some(Alignment::Left), Some(Alignment::Right)]
+        );
+    }
+
+    #[test]
+    fn widths_with_priority() {
+        let table = Table::default().widths_with_priority([
+            (Constraint::Length(5), ColumnPriority::Required),
+            (Constraint::Percentage(50), ColumnPriority::Weak),
+        ]);
+        assert_eq!(
+            table.widths,
+            vec![Constraint::Length(5), Constraint::Percentage(50)]
+        );
+        assert_eq!(
+            table.column_priorities,
+            vec![Some(ColumnPriority::Required), Some(ColumnPriority::Weak)]
+        );
+    }
+
+    #[test]
+    fn column_alignment_fills_the_gap_with_none() {
+        let table = Table::default().column_alignment(2, Alignment::Center);
+        assert_eq!(
+            table.column_alignments,
+            vec![None, None, Some(Alignment::Center)]
+        );
+    }
+
+    #[test]
+    fn column_alignment_overrides_a_single_column() {
+        let table = Table::default()
+            .column_alignments([Alignment::Left, Alignment::Left])
+            .column_alignment(1, Alignment::Right);
+        assert_eq!(
+            table.column_alignments,
+            vec![Some(Alignment::Left), Some(Alignment::Right)]
+        );
+    }
+
+    #[test]
+    fn cell_overflow() {
+        let table = Table::default().cell_overflow(CellOverflow::Ellipsis);
+        assert_eq!(table.cell_overflow, CellOverflow::Ellipsis);
+    }
+
+    #[test]
+    fn overflow_marker() {
+        let table = Table::default().overflow_marker("...");
+        assert_eq!(table.overflow_marker, "...");
+    }
+
+    #[test]
+    fn freeze_first_column() {
+        let table = Table::default().freeze_first_column(true);
+        assert!(table.freeze_first_column);
+    }
+
+    #[test]
+    fn highlight_column_style() {
+        let style = Style::default().red().italic();
+        let table = Table::default().highlight_column_style(style);
+        assert_eq!(table.highlight_column_style, style);
+    }
+
+    #[test]
+    fn cell_highlight_style() {
+        let style = Style::default().red().italic();
+        let table = Table::default().cell_highlight_style(style);
+        assert_eq!(table.cell_highlight_style, style);
+    }
+
+    #[test]
+    fn row_style_fn() {
+        let even = Style::default();
+        let odd = Style::new().bg(Color::DarkGray);
+        let table =
+            Table::default().row_style_fn(move |index| if index % 2 == 0 { even } else { odd });
+        assert_eq!(table.row_style(0), Some(even));
+        assert_eq!(table.row_style(1), Some(odd));
+        assert_eq!(table.row_style(2), Some(even));
+    }
+
+    #[test]
+    fn row_style_fn_defaults_to_none() {
+        let table = Table::default();
+        assert_eq!(table.row_style(0), None);
+    }
+
+    #[test]
+    fn alternating_row_styles() {
+        let even = Style::default();
+        let odd = Style::new().bg(Color::DarkGray);
+        let table = Table::default().alternating_row_styles(even, odd);
+        assert_eq!(table.row_style(0), Some(even));
+        assert_eq!(table.row_style(1), Some(odd));
+        assert_eq!(table.row_style(2), Some(even));
+    }
+
+    #[test]
+    fn visible_column_indices_without_freeze_scrolls_all_columns() {
+        let table = Table::default();
+        assert_eq!(table.visible_column_indices(4, 0, 4), [0, 1, 2, 3]);
+        assert_eq!(table.visible_column_indices(4, 2, 4), [2, 3]);
+        // an offset past the last column clamps to it rather than leaving nothing visible
+        assert_eq!(table.visible_column_indices(4, 10, 4), [3]);
+    }
+
+    #[test]
+    fn visible_column_indices_with_freeze_pins_the_first_column() {
+        let table = Table::default().freeze_first_column(true);
+        assert_eq!(table.visible_column_indices(4, 0, 4), [0, 1, 2, 3]);
+        assert_eq!(table.visible_column_indices(4, 1, 4), [0, 2, 3]);
+        assert_eq!(table.visible_column_indices(4, 10, 4), [0, 3]);
+    }
+
+    #[test]
+    fn visible_column_indices_stops_at_end_when_columns_overflow() {
+        let table = Table::default();
+        // 4 columns, but only 2 fit in the available width: must not run past `end`.
+        assert_eq!(table.visible_column_indices(4, 0, 2), [0, 1]);
+    }
+
+    #[test]
+    fn visible_column_indices_with_freeze_stops_at_end_when_columns_overflow() {
+        let table = Table::default().freeze_first_column(true);
+        // 4 columns of width 10 + 1 spacing, max_width 30: column 0 is frozen and only one more
+        // column fits in the remaining width, so the window must not spill past `end`.
+        assert_eq!(table.visible_column_indices(4, 0, 2), [0, 1]);
+    }
+
+    #[test]
+    fn get_column_bounds_fills_as_many_columns_as_fit_from_the_offset() {
+        let table = Table::default();
+        let widths = [(5, 6), (5, 6), (5, 6), (5, 6)];
+        assert_eq!(table.get_column_bounds(Some(0), 0, 13, &widths), (0, 2));
+        assert_eq!(table.get_column_bounds(Some(1), 1, 13, &widths), (1, 3));
+    }
+
+    #[test]
+    fn get_column_bounds_scrolls_right_to_keep_the_selected_column_visible() {
+        let table = Table::default();
+        let widths = [(5, 6), (5, 6), (5, 6), (5, 6)];
+        // columns 0 and 1 fit in a width of 13; selecting column 3 has to scroll right
+        assert_eq!(table.get_column_bounds(Some(3), 0, 13, &widths), (2, 4));
+    }
+
+    #[test]
+    fn get_column_bounds_scrolls_left_to_keep_the_selected_column_visible() {
+        let table = Table::default();
+        let widths = [(5, 6), (5, 6), (5, 6), (5, 6)];
+        // starting scrolled to the last columns, selecting column 0 has to scroll back left
+        assert_eq!(table.get_column_bounds(Some(0), 2, 13, &widths), (0, 2));
+    }
+
+    #[test]
+    fn get_column_bounds_with_no_columns_is_empty() {
+        let table = Table::default();
+        assert_eq!(table.get_column_bounds(None, 0, 13, &[]), (0, 0));
+    }
+
+    #[test]
+    fn auto_row_height_uses_the_tallest_wrapped_cell() {
+        let row = Row::new(vec![Cell::from("a b c d"), Cell::from("short")]);
+        let columns = [(0, 0, 3), (1, 4, 10)];
+        assert_eq!(auto_row_height(&row, &columns), 3);
+    }
+
+    #[test]
+    fn auto_row_height_never_shrinks_below_the_explicit_row_height() {
+        let row = Row::new(vec![Cell::from("x")]).height(5);
+        let columns = [(0, 0, 10)];
+        assert_eq!(auto_row_height(&row, &columns), 5);
+    }
+
+    #[test]
+    fn auto_row_height_skips_zero_width_columns() {
+        let row = Row::new(vec![Cell::from("a b c d"), Cell::from("short")]);
+        let columns = [(0, 0, 0), (1, 0, 10)];
+        assert_eq!(auto_row_height(&row, &columns), 1);
+    }
+
     #[test]
     fn widths_conversions() {
         let array = [Constraint::Percentage(100)];
@@ -1143,6 +2442,29 @@ mod tests {
             assert_buffer_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_row_height_auto_wraps_long_cell_text() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+            let rows = vec![Row::new(vec!["a b c d"])];
+            let table = Table::new(rows, [Constraint::Length(3)]).row_height_auto(true);
+            Widget::render(table, Rect::new(0, 0, 3, 3), &mut buf);
+            let expected = Buffer::with_lines(vec!["a b", " c ", "d  "]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_row_height_auto_keeps_highlight_symbol_on_the_first_visual_line() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+            let rows = vec![Row::new(vec!["abcdef"])];
+            let table = Table::new(rows, [Constraint::Length(3)])
+                .row_height_auto(true)
+                .highlight_symbol(">>");
+            let mut state = TableState::new().with_selected(0);
+            StatefulWidget::render(table, Rect::new(0, 0, 5, 2), &mut buf, &mut state);
+            let expected = Buffer::with_lines(vec![">>abc", "  def"]);
+            assert_buffer_eq!(buf, expected);
+        }
+
         #[test]
         fn render_with_alignment() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
@@ -1161,6 +2483,92 @@ mod tests {
             assert_buffer_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_column_alignment() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+            let rows = vec![Row::new(vec!["1", "2"])];
+            let table = Table::new(rows, [Constraint::Length(3); 2])
+                .column_spacing(0)
+                .column_alignments([Alignment::Left, Alignment::Right]);
+            Widget::render(table, Rect::new(0, 0, 6, 1), &mut buf);
+            let expected = Buffer::with_lines(vec!["1    2"]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_column_alignment_overridden_by_cell() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+            let rows = vec![Row::new(vec![Cell::from(
+                Line::from("2").alignment(Alignment::Left),
+            )])];
+            let table =
+                Table::new(rows, [Constraint::Length(3)]).column_alignment(0, Alignment::Right);
+            Widget::render(table, Rect::new(0, 0, 3, 1), &mut buf);
+            let expected = Buffer::with_lines(vec!["2  "]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_column_alignment_respects_spacing_and_selection_offset() {
+            // Right-aligned padding must stay inside its own column's `Rect`: it should never
+            // eat into the `column_spacing` gap, and it must still land in the right place once
+            // the selection highlight symbol has shifted every column's `x` over.
+            let mut buf = Buffer::empty(Rect::new(0, 0, 9, 1));
+            let rows = vec![Row::new(vec!["1", "22"])];
+            let table = Table::new(rows, [Constraint::Length(3); 2])
+                .column_spacing(1)
+                .column_alignments([Alignment::Left, Alignment::Right])
+                .highlight_symbol(">>");
+            let mut state = TableState::new().with_selected(0);
+            StatefulWidget::render(table, Rect::new(0, 0, 9, 1), &mut buf, &mut state);
+            let expected = Buffer::with_lines(vec![">>1    22"]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_cell_overflow_ellipsis_truncates_long_text() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+            let rows = vec![Row::new(vec!["Hello, world!"])];
+            let table =
+                Table::new(rows, [Constraint::Length(5)]).cell_overflow(CellOverflow::Ellipsis);
+            Widget::render(table, Rect::new(0, 0, 5, 1), &mut buf);
+            let expected = Buffer::with_lines(vec!["Hell…"]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_cell_overflow_ellipsis_leaves_short_text_alone() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+            let rows = vec![Row::new(vec!["Hi"])];
+            let table =
+                Table::new(rows, [Constraint::Length(5)]).cell_overflow(CellOverflow::Ellipsis);
+            Widget::render(table, Rect::new(0, 0, 5, 1), &mut buf);
+            let expected = Buffer::with_lines(vec!["Hi   "]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_custom_overflow_marker() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+            let rows = vec![Row::new(vec!["Hello, world!"])];
+            let table = Table::new(rows, [Constraint::Length(5)])
+                .cell_overflow(CellOverflow::Ellipsis)
+                .overflow_marker("~");
+            Widget::render(table, Rect::new(0, 0, 5, 1), &mut buf);
+            let expected = Buffer::with_lines(vec!["Hell~"]);
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_cell_overflow_wrap_breaks_onto_extra_lines() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 3, 3));
+            let rows = vec![Row::new(vec!["a b c d"]).height(3)];
+            let table = Table::new(rows, [Constraint::Length(3)]).cell_overflow(CellOverflow::Wrap);
+            Widget::render(table, Rect::new(0, 0, 3, 3), &mut buf);
+            let expected = Buffer::with_lines(vec!["a b", " c ", "d  "]);
+            assert_buffer_eq!(buf, expected);
+        }
+
         #[test]
         fn render_with_overflow_does_not_panic() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 20, 3));
@@ -1189,6 +2597,73 @@ mod tests {
             ]);
             assert_buffer_eq!(buf, expected);
         }
+
+        #[test]
+        fn render_with_cell_highlight_style() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .highlight_style(Style::new().red())
+                .cell_highlight_style(Style::new().reversed());
+            let mut state = TableState::new().with_selected(0).with_selected_column(1);
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 3), &mut buf, &mut state);
+            let mut expected = Buffer::with_lines(vec![
+                "Cell1 Cell2    ".red(),
+                "Cell3 Cell4    ".into(),
+                "               ".into(),
+            ]);
+            expected.set_style(Rect::new(6, 0, 5, 1), Style::new().red().reversed());
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_alternating_row_styles() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+                Row::new(vec!["Cell5", "Cell6"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .alternating_row_styles(Style::default(), Style::new().bg(Color::DarkGray));
+            Widget::render(table, Rect::new(0, 0, 15, 3), &mut buf);
+            let mut expected = Buffer::with_lines(vec![
+                "Cell1 Cell2    ",
+                "Cell3 Cell4    ",
+                "Cell5 Cell6    ",
+            ]);
+            expected.set_style(Rect::new(0, 1, 15, 1), Style::new().bg(Color::DarkGray));
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_alternating_row_styles_lets_explicit_row_style_win() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 2));
+            let rows = vec![Row::new(vec!["Cell1", "Cell2"]).style(Style::new().green())];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .alternating_row_styles(Style::default(), Style::new().bg(Color::DarkGray));
+            Widget::render(table, Rect::new(0, 0, 15, 2), &mut buf);
+            let mut expected = Buffer::with_lines(vec!["Cell1 Cell2    ", "               "]);
+            expected.set_style(Rect::new(0, 0, 15, 1), Style::new().green());
+            assert_buffer_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_alternating_row_styles_lets_highlight_style_win_on_the_selected_row() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 2));
+            let rows = vec![Row::new(vec!["Cell1", "Cell2"])];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .alternating_row_styles(Style::default(), Style::new().bg(Color::DarkGray))
+                .highlight_style(Style::new().red());
+            let mut state = TableState::new().with_selected(0);
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 2), &mut buf, &mut state);
+            let mut expected = Buffer::with_lines(vec!["Cell1 Cell2    ", "               "]);
+            expected.set_style(Rect::new(0, 0, 15, 1), Style::new().red());
+            assert_buffer_eq!(buf, expected);
+        }
     }
 
     // test how constraints interact with table column width allocation
@@ -1369,6 +2844,243 @@ mod tests {
             assert_eq!(table.get_columns_widths(10, 0), [(0, 5), (5, 5)])
         }
 
+        // `ColumnSizing::Cassowary` only pins down an exact answer once every soft preference is
+        // simultaneously satisfiable (no slack to distribute); under real pressure the simplex
+        // solver is free to break ties between equally-weighted columns however it likes, so
+        // those cases are asserted on total/bounds instead of exact per-column widths.
+        mod cassowary {
+            use super::*;
+
+            #[test]
+            fn length_constraints_with_exact_fit() {
+                let table = Table::default()
+                    .widths([Length(4), Length(6)])
+                    .column_sizing(ColumnSizing::Cassowary);
+                assert_eq!(table.get_columns_widths(11, 0), [(0, 4), (5, 6)]);
+            }
+
+            #[test]
+            fn min_and_max_constraints_with_exact_fit() {
+                let table = Table::default()
+                    .widths([Min(5), Max(8)])
+                    .column_sizing(ColumnSizing::Cassowary);
+                assert_eq!(table.get_columns_widths(14, 0), [(0, 5), (6, 8)]);
+            }
+
+            #[test]
+            fn percentage_constraints_with_exact_fit() {
+                let table = Table::default()
+                    .widths([Percentage(50), Percentage(50)])
+                    .column_sizing(ColumnSizing::Cassowary);
+                assert_eq!(table.get_columns_widths(11, 0), [(0, 5), (6, 5)]);
+            }
+
+            #[test]
+            fn no_columns_produces_no_widths() {
+                let table = Table::default().column_sizing(ColumnSizing::Cassowary);
+                assert_eq!(table.get_columns_widths(20, 0), Vec::<(u16, u16)>::new());
+            }
+
+            #[test]
+            fn columns_shrink_to_fit_rather_than_overflow_under_pressure() {
+                let table = Table::default()
+                    .widths([Min(10), Min(10), Min(10)])
+                    .column_spacing(1)
+                    .column_sizing(ColumnSizing::Cassowary);
+                let widths = table.get_columns_widths(15, 0);
+                let allocated: u16 =
+                    widths.iter().map(|&(_, w)| w).sum::<u16>() + table.column_spacing * 2;
+                assert!(allocated.abs_diff(15) <= 1);
+            }
+
+            #[test]
+            fn required_priority_keeps_its_exact_width_under_pressure() {
+                let table = Table::default()
+                    .widths_with_priority([
+                        (Length(10), ColumnPriority::Required),
+                        (Length(10), ColumnPriority::Medium),
+                    ])
+                    .column_spacing(0)
+                    .column_sizing(ColumnSizing::Cassowary);
+                assert_eq!(table.get_columns_widths(15, 0), [(0, 10), (10, 5)]);
+            }
+
+            #[test]
+            fn weak_priority_shrinks_before_medium_under_pressure() {
+                let table = Table::default()
+                    .widths_with_priority([
+                        (Length(10), ColumnPriority::Weak),
+                        (Length(10), ColumnPriority::Medium),
+                    ])
+                    .column_spacing(0)
+                    .column_sizing(ColumnSizing::Cassowary);
+                assert_eq!(table.get_columns_widths(15, 0), [(0, 5), (5, 10)]);
+            }
+
+            #[test]
+            fn weak_priority_absorbs_the_surplus_left_over_by_medium() {
+                let table = Table::default()
+                    .widths_with_priority([
+                        (Length(5), ColumnPriority::Weak),
+                        (Length(5), ColumnPriority::Medium),
+                    ])
+                    .column_spacing(0)
+                    .column_sizing(ColumnSizing::Cassowary);
+                assert_eq!(table.get_columns_widths(20, 0), [(0, 15), (15, 5)]);
+            }
+
+            /// Regression test: `Min`'s preferred size used to be pinned with the column's
+            /// configured (default `Medium`) priority, the same as `Length`, so it never flexed
+            /// to take up slack on its own. `Min` is supposed to be the elastic one, mirroring
+            /// the original tui-rs behavior, so all of the slack here should land on it rather
+            /// than being split evenly with the fixed `Length` column.
+            #[test]
+            fn min_priority_absorbs_all_the_slack_left_over_by_length() {
+                let table = Table::default()
+                    .widths([Min(0), Length(10)])
+                    .column_spacing(0)
+                    .column_sizing(ColumnSizing::Cassowary);
+                assert_eq!(table.get_columns_widths(20, 0), [(0, 10), (10, 10)]);
+            }
+        }
+
+        mod expand {
+            use super::*;
+
+            #[test]
+            fn min_columns_grow_to_fill_the_remaining_width() {
+                // Ties between equally-weighted growable columns are left to the solver, so this
+                // only asserts the totals and that the slack was shared rather than dumped on one
+                // column, mirroring how `mod cassowary`'s own under-pressure test is asserted.
+                let table = Table::default()
+                    .widths([Min(4), Min(4)])
+                    .column_spacing(1)
+                    .column_sizing(ColumnSizing::Expand);
+                let widths = table.get_columns_widths(20, 0);
+                let allocated: u16 =
+                    widths.iter().map(|&(_, w)| w).sum::<u16>() + table.column_spacing;
+                assert_eq!(allocated, 20);
+                let [(_, a), (_, b)] = widths[..] else {
+                    panic!("expected two columns");
+                };
+                assert!(a.abs_diff(b) <= 1);
+            }
+
+            #[test]
+            fn an_odd_remainder_goes_to_the_largest_fractional_share() {
+                let table = Table::default()
+                    .widths([Min(0), Min(0), Min(0)])
+                    .column_spacing(0)
+                    .column_sizing(ColumnSizing::Expand);
+                let widths = table.get_columns_widths(10, 0);
+                let total: u16 = widths.iter().map(|&(_, w)| w).sum();
+                assert_eq!(total, 10);
+            }
+
+            #[test]
+            fn length_columns_keep_their_exact_size_while_min_columns_absorb_the_rest() {
+                let table = Table::default()
+                    .widths([Length(4), Min(0)])
+                    .column_spacing(1)
+                    .column_sizing(ColumnSizing::Expand);
+                assert_eq!(table.get_columns_widths(20, 0), [(0, 4), (5, 15)]);
+            }
+
+            #[test]
+            fn no_columns_produces_no_widths() {
+                let table = Table::default().column_sizing(ColumnSizing::Expand);
+                assert_eq!(table.get_columns_widths(20, 0), Vec::<(u16, u16)>::new());
+            }
+
+            #[test]
+            fn proportional_weights_share_the_remaining_space_by_weight() {
+                let table = Table::default()
+                    .widths([Proportional(1), Proportional(3)])
+                    .column_spacing(0)
+                    .column_sizing(ColumnSizing::Expand);
+                assert_eq!(table.get_columns_widths(16, 0), [(0, 4), (4, 12)]);
+            }
+
+            #[test]
+            fn proportional_weights_only_share_the_space_left_after_pinned_columns() {
+                let table = Table::default()
+                    .widths([Length(4), Proportional(1), Proportional(3)])
+                    .column_spacing(0)
+                    .column_sizing(ColumnSizing::Expand);
+                assert_eq!(table.get_columns_widths(20, 0), [(0, 4), (4, 4), (8, 12)]);
+            }
+        }
+
+        mod content_fit {
+            use super::*;
+
+            #[test]
+            fn columns_get_their_exact_natural_width_when_it_all_fits() {
+                let table = Table::default()
+                    .rows(vec![Row::new(vec!["ab", "abcde"])])
+                    .column_spacing(1)
+                    .column_sizing(ColumnSizing::ContentFit);
+                assert_eq!(table.get_columns_widths(8, 0), [(0, 2), (3, 5)]);
+            }
+
+            #[test]
+            fn header_and_footer_widen_a_column_beyond_its_row_cells() {
+                let table = Table::default()
+                    .rows(vec![Row::new(vec!["a"])])
+                    .header(Row::new(vec!["abcd"]))
+                    .column_sizing(ColumnSizing::ContentFit);
+                assert_eq!(table.get_columns_widths(10, 0), [(0, 4)]);
+            }
+
+            #[test]
+            fn no_columns_produces_no_widths() {
+                let table = Table::default().column_sizing(ColumnSizing::ContentFit);
+                assert_eq!(table.get_columns_widths(20, 0), Vec::<(u16, u16)>::new());
+            }
+
+            #[test]
+            fn narrow_columns_are_locked_in_full_while_wide_ones_shrink_to_the_fair_share() {
+                // "ab" (width 2) is under the 5-wide fair share of (15 / 3) on the first round, so
+                // it is locked in full; the remaining 13 is then split between the two 10-wide
+                // columns, which both shrink to 6 and 7.
+                let table = Table::default()
+                    .rows(vec![Row::new(vec!["ab", "0123456789", "0123456789"])])
+                    .column_spacing(0)
+                    .column_sizing(ColumnSizing::ContentFit);
+                let widths = table.get_columns_widths(15, 0);
+                assert_eq!(widths[0], (0, 2));
+                let total: u16 = widths.iter().map(|&(_, w)| w).sum();
+                assert_eq!(total, 15);
+            }
+
+            #[test]
+            fn floor_never_pushes_the_total_past_available_under_extreme_pressure() {
+                // 3 columns can't all keep a floor of 1 cell when only 2 cells are available;
+                // the narrowest one collapses to 0 rather than the total overflowing `available`
+                // (see the regression test below for the case where there's room to spare).
+                let table = Table::default()
+                    .rows(vec![Row::new(vec!["0123456789", "0123456789", "0123456789"])])
+                    .column_spacing(0)
+                    .column_sizing(ColumnSizing::ContentFit);
+                let widths = table.get_columns_widths(2, 0);
+                assert_eq!(widths.iter().map(|&(_, w)| w).sum::<u16>(), 2);
+                assert_eq!(widths.iter().filter(|&&(_, w)| w == 0).count(), 1);
+            }
+
+            /// Regression test: the floor used to be applied unconditionally (`w.max(1)` on
+            /// every column), so a column that fair-share had dropped to 0 would get bumped back
+            /// up to 1 even when that pushed the grand total past `available`. This exercises
+            /// `shrink_to_fair_share` directly with a natural width of 0, which
+            /// `natural_column_widths` never actually produces (every column is floored at 1
+            /// there), to pin down the reclaim behavior in isolation: the cell needed to raise
+            /// the 0-width column to 1 comes out of the other, wider column instead.
+            #[test]
+            fn floor_is_reclaimed_from_the_widest_column_when_there_is_room_to_spare() {
+                let widths = Table::shrink_to_fair_share(&[5, 0], 5);
+                assert_eq!(widths, [4, 1]);
+            }
+        }
+
         fn test_table_with_selection(
             highlight_spacing: HighlightSpacing,
             columns: u16,
@@ -0,0 +1,155 @@
+/// State of a [`Table`] widget
+///
+/// This state can be used to scroll through the rows and select one of them, as well as to
+/// horizontally scroll through and select one of the columns once [`Table::widths`] no longer
+/// fits the area the table is rendered into.
+///
+/// [`Table`]: super::Table
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct TableState {
+    pub(crate) offset: usize,
+    pub(crate) selected: Option<usize>,
+    pub(crate) column_offset: usize,
+    pub(crate) selected_column: Option<usize>,
+}
+
+impl TableState {
+    /// Creates a new [`TableState`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::widgets::TableState;
+    /// let state = TableState::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            offset: 0,
+            selected: None,
+            column_offset: 0,
+            selected_column: None,
+        }
+    }
+
+    /// Sets the index of the first row to be displayed
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the index of the selected row
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_selected<T: Into<Option<usize>>>(mut self, selected: T) -> Self {
+        self.selected = selected.into();
+        self
+    }
+
+    /// Sets the index of the first column to be displayed once [`Table::widths`] no longer fits
+    /// the area the table is rendered into.
+    ///
+    /// See [`TableState::scroll_right_column`]/[`TableState::scroll_left_column`] to adjust this
+    /// incrementally instead of setting it outright.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_column_offset(mut self, column_offset: usize) -> Self {
+        self.column_offset = column_offset;
+        self
+    }
+
+    /// Sets the index of the selected column, which [`Table::highlight_column_style`] highlights
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_selected_column<T: Into<Option<usize>>>(mut self, selected_column: T) -> Self {
+        self.selected_column = selected_column.into();
+        self
+    }
+
+    /// Index of the first row currently displayed
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Mutable reference to the index of the first row currently displayed
+    pub fn offset_mut(&mut self) -> &mut usize {
+        &mut self.offset
+    }
+
+    /// Index of the selected row, if any
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Mutable reference to the index of the selected row, if any
+    pub fn selected_mut(&mut self) -> &mut Option<usize> {
+        &mut self.selected
+    }
+
+    /// Sets the selected row, resetting the scroll offset when the selection is cleared
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+        if index.is_none() {
+            self.offset = 0;
+        }
+    }
+
+    /// Index of the first column currently displayed, once [`Table::widths`] no longer fits the
+    /// area the table is rendered into
+    pub const fn column_offset(&self) -> usize {
+        self.column_offset
+    }
+
+    /// Mutable reference to the index of the first column currently displayed
+    pub fn column_offset_mut(&mut self) -> &mut usize {
+        &mut self.column_offset
+    }
+
+    /// Index of the selected column, if any
+    pub const fn selected_column(&self) -> Option<usize> {
+        self.selected_column
+    }
+
+    /// Mutable reference to the index of the selected column, if any
+    pub fn selected_column_mut(&mut self) -> &mut Option<usize> {
+        &mut self.selected_column
+    }
+
+    /// Index of the selected `(row, column)` cell, if both a row and a column are selected
+    ///
+    /// This is the cell [`Table::cell_highlight_style`] is painted onto.
+    ///
+    /// [`Table::cell_highlight_style`]: super::Table::cell_highlight_style
+    pub const fn selected_cell(&self) -> Option<(usize, usize)> {
+        match (self.selected, self.selected_column) {
+            (Some(row), Some(column)) => Some((row, column)),
+            _ => None,
+        }
+    }
+
+    /// Sets the selected column, resetting the column scroll offset when the selection is cleared
+    pub fn select_column(&mut self, index: Option<usize>) {
+        self.selected_column = index;
+        if index.is_none() {
+            self.column_offset = 0;
+        }
+    }
+
+    /// Scrolls one column to the right, moving [`TableState::column_offset`] forward by one.
+    ///
+    /// Rendering clamps the offset to the number of columns actually present, so this is always
+    /// safe to call regardless of how wide the table is.
+    pub fn scroll_right_column(&mut self) {
+        self.column_offset = self.column_offset.saturating_add(1);
+    }
+
+    /// Scrolls one column to the left, moving [`TableState::column_offset`] back by one.
+    pub fn scroll_left_column(&mut self) {
+        self.column_offset = self.column_offset.saturating_sub(1);
+    }
+}